@@ -1,11 +1,22 @@
 use crate::{
-    bucket::{IamPolicy, TestIamPermission},
+    bucket::{
+        IamPolicy, Lifecycle, ReadParameters, Rpo, TestIamPermission, UpdateParameters, Website,
+    },
     error::GoogleResponse,
-    object::percent_encode,
+    object::{percent_encode, PredefinedAcl},
     resources::common::ListResponse,
     Bucket, NewBucket,
 };
 
+// The query parameters of a bucket insert request.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateQuery<'a> {
+    project: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    predefined_default_object_acl: Option<PredefinedAcl>,
+}
+
 /// Operations on [`Bucket`]()s.
 #[derive(Debug)]
 pub struct BucketClient<'a>(pub(super) &'a super::Client);
@@ -36,16 +47,20 @@ impl<'a> BucketClient<'a> {
     /// ```
     pub async fn create(&self, new_bucket: &NewBucket) -> crate::Result<Bucket> {
         let url = format!("{}/b/", crate::BASE_URL);
-        let project = &crate::SERVICE_ACCOUNT.project_id;
-        let query = [("project", project)];
+        let query = CreateQuery {
+            project: &self.0.service_account().project_id,
+            predefined_default_object_acl: new_bucket.predefined_default_object_acl,
+        };
         let result: GoogleResponse<Bucket> = self
             .0
-            .client
-            .post(&url)
-            .headers(self.0.get_headers().await?)
-            .query(&query)
-            .json(new_bucket)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&query)
+                    .json(new_bucket),
+            )
             .await?
             .json()
             .await?;
@@ -74,15 +89,17 @@ impl<'a> BucketClient<'a> {
     /// ```
     pub async fn list(&self) -> crate::Result<Vec<Bucket>> {
         let url = format!("{}/b/", crate::BASE_URL);
-        let project = &crate::SERVICE_ACCOUNT.project_id;
+        let project = &self.0.service_account().project_id;
         let query = [("project", project)];
         let result: GoogleResponse<ListResponse<Bucket>> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .query(&query)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&query),
+            )
             .await?
             .json()
             .await?;
@@ -117,10 +134,7 @@ impl<'a> BucketClient<'a> {
         let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(name),);
         let result: GoogleResponse<Bucket> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -130,6 +144,102 @@ impl<'a> BucketClient<'a> {
         }
     }
 
+    /// Returns a single `Bucket` by its name, using the provided `ReadParameters`. If
+    /// `if_metageneration_not_match` is not met, the bucket has not changed and `None` is
+    /// returned, which can be used to only fetch the bucket metadata again when it has changed. If
+    /// `if_metageneration_match` is not met, the bucket did change and `Error::PreconditionFailed`
+    /// is returned, just like for `update_with`.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::ReadParameters;
+    ///
+    /// let client = Client::default();
+    /// let bucket = client.bucket().read("my-bucket").await?;
+    /// let parameters = ReadParameters {
+    ///     if_metageneration_not_match: Some(bucket.metageneration),
+    ///     ..Default::default()
+    /// };
+    /// match client.bucket().read_with("my-bucket", &parameters).await? {
+    ///     Some(changed) => println!("updated at {}", changed.updated),
+    ///     None => println!("unchanged since the last read"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_with(
+        &self,
+        name: &str,
+        parameters: &ReadParameters,
+    ) -> crate::Result<Option<Bucket>> {
+        use reqwest::StatusCode;
+
+        let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(name));
+        let response = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(parameters),
+            )
+            .await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        match response.json().await? {
+            GoogleResponse::Success(s) => Ok(Some(s)),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Checks whether a bucket with the specified name exists. Only the name of the bucket is
+    /// requested, rather than all of its metadata. If the bucket does not exist, `false` is
+    /// returned, while any other failure, such as missing permissions, is returned as an error.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::NewBucket;
+    ///
+    /// let client = Client::default();
+    /// if !client.bucket().exists("my-bucket").await? {
+    ///     let new_bucket = NewBucket {
+    ///         name: "my-bucket".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///     client.bucket().create(&new_bucket).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists(&self, name: &str) -> crate::Result<bool> {
+        let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(name));
+        let response = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&[("fields", "name")]),
+            )
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(false)
+        } else if response.status().is_success() {
+            Ok(true)
+        } else {
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
+        }
+    }
+
     /// Update an existing `Bucket`. If you declare you bucket as mutable, you can edit its fields.
     /// You can then flush your changes to Google Cloud Storage using this method.
     /// ### Example
@@ -159,14 +269,190 @@ impl<'a> BucketClient<'a> {
     /// # }
     /// ```
     pub async fn update(&self, bucket: &Bucket) -> crate::Result<Bucket> {
+        self.update_with(bucket, &UpdateParameters::default()).await
+    }
+
+    /// Updates a bucket in the same way as `update`, using the query parameters in `parameters`.
+    /// Its preconditions make sure the bucket is only updated if it still meets them. Passing the
+    /// metageneration of the bucket as it was read makes sure that no one else changed its
+    /// configuration in the meantime, rather than silently overwriting their changes. If the
+    /// bucket did change, `Error::PreconditionFailed` is returned. A predefined default object ACL
+    /// can be applied in the same request.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::{Preconditions, UpdateParameters, Versioning};
+    /// use cloud_storage::object::PredefinedAcl;
+    ///
+    /// let client = Client::default();
+    /// let mut bucket = client.bucket().read("my-bucket").await?;
+    /// let parameters = UpdateParameters {
+    ///     predefined_default_object_acl: Some(PredefinedAcl::ProjectPrivate),
+    ///     preconditions: Preconditions {
+    ///         if_metageneration_match: Some(bucket.metageneration),
+    ///         ..Default::default()
+    ///     },
+    /// };
+    /// bucket.versioning = Some(Versioning { enabled: true });
+    /// client.bucket().update_with(&bucket, &parameters).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_with(
+        &self,
+        bucket: &Bucket,
+        parameters: &UpdateParameters,
+    ) -> crate::Result<Bucket> {
         let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(&bucket.name),);
         let result: GoogleResponse<Bucket> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(bucket)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(parameters)
+                    .json(bucket),
+            )
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Configures the bucket with the specified name to serve its contents as a static website,
+    /// using the index and 404 pages in `website`. Only the `website` field of the bucket is
+    /// changed.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::Website;
+    ///
+    /// let client = Client::default();
+    /// let website = Website {
+    ///     main_page_suffix: "index.html".to_string(),
+    ///     not_found_page: "404.html".to_string(),
+    /// };
+    /// client.bucket().set_website("my-bucket", &website).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_website(&self, name: &str, website: &Website) -> crate::Result<Bucket> {
+        self.patch(name, &serde_json::json!({ "website": website }))
+            .await
+    }
+
+    /// Removes the static website configuration from the bucket with the specified name. Only
+    /// the `website` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// client.bucket().clear_website("my-bucket").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clear_website(&self, name: &str) -> crate::Result<Bucket> {
+        self.patch(name, &serde_json::json!({ "website": null }))
+            .await
+    }
+
+    /// Replaces the lifecycle rules of the bucket with the specified name with `lifecycle`. Only
+    /// the `lifecycle` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::Lifecycle;
+    ///
+    /// let client = Client::default();
+    /// let lifecycle = Lifecycle::delete_after_days(30);
+    /// client.bucket().set_lifecycle("my-bucket", &lifecycle).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_lifecycle(&self, name: &str, lifecycle: &Lifecycle) -> crate::Result<Bucket> {
+        self.patch(name, &serde_json::json!({ "lifecycle": lifecycle }))
+            .await
+    }
+
+    /// Sets the recovery point objective of the dual-region bucket with the specified name to
+    /// `rpo`. Only the `rpo` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::Rpo;
+    ///
+    /// let client = Client::default();
+    /// client.bucket().set_rpo("my-dual-region-bucket", Rpo::AsyncTurbo).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_rpo(&self, name: &str, rpo: Rpo) -> crate::Result<Bucket> {
+        self.patch(name, &serde_json::json!({ "rpo": rpo })).await
+    }
+
+    /// Sets the default access controls of new objects in the bucket with the specified name to
+    /// the predefined set `acl`. Objects that already exist keep their access controls.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::PredefinedAcl;
+    ///
+    /// let client = Client::default();
+    /// client
+    ///     .bucket()
+    ///     .set_predefined_default_object_acl("my-bucket", PredefinedAcl::PublicRead)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_predefined_default_object_acl(
+        &self,
+        name: &str,
+        acl: PredefinedAcl,
+    ) -> crate::Result<Bucket> {
+        let query = [("predefinedDefaultObjectAcl", acl)];
+        self.patch_with(name, &query, &serde_json::json!({})).await
+    }
+
+    async fn patch(&self, name: &str, body: &serde_json::Value) -> crate::Result<Bucket> {
+        let query: [(&str, &str); 0] = [];
+        self.patch_with(name, &query, body).await
+    }
+
+    async fn patch_with(
+        &self,
+        name: &str,
+        query: &impl serde::Serialize,
+        body: &serde_json::Value,
+    ) -> crate::Result<Bucket> {
+        let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(name));
+        let result: GoogleResponse<Bucket> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .patch(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(query)
+                    .json(body),
+            )
             .await?
             .json()
             .await?;
@@ -203,15 +489,19 @@ impl<'a> BucketClient<'a> {
         let url = format!("{}/b/{}", crate::BASE_URL, percent_encode(&bucket.name));
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
         }
     }
 
@@ -241,10 +531,7 @@ impl<'a> BucketClient<'a> {
         let url = format!("{}/b/{}/iam", crate::BASE_URL, percent_encode(&bucket.name));
         let result: GoogleResponse<IamPolicy> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -296,11 +583,13 @@ impl<'a> BucketClient<'a> {
         let url = format!("{}/b/{}/iam", crate::BASE_URL, percent_encode(&bucket.name));
         let result: GoogleResponse<IamPolicy> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(iam)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(iam),
+            )
             .await?
             .json()
             .await?;
@@ -329,11 +618,57 @@ impl<'a> BucketClient<'a> {
         bucket: &Bucket,
         permission: &str,
     ) -> crate::Result<TestIamPermission> {
-        if permission == "storage.buckets.list" || permission == "storage.buckets.create" {
-            return Err(crate::Error::new(
-                "tested permission must not be `storage.buckets.list` or `storage.buckets.create`",
-            ));
+        self.test_permissions(bucket, &[permission]).await
+    }
+
+    /// Checks which of `permissions` the user provided in the service account has, in a single
+    /// request, and returns the ones it has.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let bucket = client.bucket().read("my-bucket").await?;
+    /// let required = ["storage.objects.get", "storage.objects.list", "storage.objects.create"];
+    /// let granted = client.bucket().test_iam_permissions(&bucket, &required).await?;
+    /// if granted.len() < required.len() {
+    ///     println!("missing permissions, only have {:?}", granted);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_iam_permissions(
+        &self,
+        bucket: &Bucket,
+        permissions: &[&str],
+    ) -> crate::Result<Vec<String>> {
+        if permissions.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .test_permissions(bucket, permissions)
+            .await?
+            .permissions)
+    }
+
+    async fn test_permissions(
+        &self,
+        bucket: &Bucket,
+        permissions: &[&str],
+    ) -> crate::Result<TestIamPermission> {
+        for &permission in permissions {
+            if permission == "storage.buckets.list" || permission == "storage.buckets.create" {
+                return Err(crate::Error::new(
+                    "tested permission must not be `storage.buckets.list` or `storage.buckets.create`",
+                ));
+            }
         }
+        let query: Vec<_> = permissions
+            .iter()
+            .map(|&permission| ("permissions", permission))
+            .collect();
         let url = format!(
             "{}/b/{}/iam/testPermissions",
             crate::BASE_URL,
@@ -341,11 +676,13 @@ impl<'a> BucketClient<'a> {
         );
         let result: GoogleResponse<TestIamPermission> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .query(&[("permissions", permission)])
-            .send()
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&query),
+            )
             .await?
             .json()
             .await?;
@@ -355,3 +692,43 @@ impl<'a> BucketClient<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predefined_default_object_acl_in_query() -> Result<(), Box<dyn std::error::Error>> {
+        let new_bucket = NewBucket {
+            name: "my-bucket".to_string(),
+            predefined_default_object_acl: Some(PredefinedAcl::PublicRead),
+            ..Default::default()
+        };
+        let query = CreateQuery {
+            project: "my-project",
+            predefined_default_object_acl: new_bucket.predefined_default_object_acl,
+        };
+        assert_eq!(
+            crate::query_of(&query).as_deref(),
+            Some("project=my-project&predefinedDefaultObjectAcl=publicRead")
+        );
+        let body = serde_json::to_value(&new_bucket)?;
+        assert!(body.get("predefinedDefaultObjectAcl").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn predefined_default_object_acl_in_update_query() {
+        let parameters = UpdateParameters {
+            predefined_default_object_acl: Some(PredefinedAcl::PublicRead),
+            preconditions: crate::bucket::Preconditions {
+                if_metageneration_match: Some(3),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            crate::query_of(&parameters).as_deref(),
+            Some("predefinedDefaultObjectAcl=publicRead&ifMetagenerationMatch=3")
+        );
+    }
+}
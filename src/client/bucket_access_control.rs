@@ -41,11 +41,13 @@ impl<'a> BucketAccessControlClient<'a> {
         let url = format!("{}/b/{}/acl", crate::BASE_URL, percent_encode(bucket));
         let result: GoogleResponse<BucketAccessControl> = self
             .0
-            .client
-            .post(&url)
-            .headers(self.0.get_headers().await?)
-            .json(new_bucket_access_control)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(new_bucket_access_control),
+            )
             .await?
             .json()
             .await?;
@@ -77,10 +79,7 @@ impl<'a> BucketAccessControlClient<'a> {
         let url = format!("{}/b/{}/acl", crate::BASE_URL, percent_encode(bucket));
         let result: GoogleResponse<ListResponse<BucketAccessControl>> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -117,10 +116,7 @@ impl<'a> BucketAccessControlClient<'a> {
         );
         let result: GoogleResponse<BucketAccessControl> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -162,11 +158,13 @@ impl<'a> BucketAccessControlClient<'a> {
         );
         let result: GoogleResponse<BucketAccessControl> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(bucket_access_control)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(bucket_access_control),
+            )
             .await?
             .json()
             .await?;
@@ -204,15 +202,19 @@ impl<'a> BucketAccessControlClient<'a> {
         );
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
         }
     }
 }
@@ -47,11 +47,13 @@ impl<'a> DefaultObjectAccessControlClient<'a> {
         );
         let result: GoogleResponse<DefaultObjectAccessControl> = self
             .0
-            .client
-            .post(&url)
-            .headers(self.0.get_headers().await?)
-            .json(new_acl)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(new_acl),
+            )
             .await?
             .json()
             .await?;
@@ -89,10 +91,7 @@ impl<'a> DefaultObjectAccessControlClient<'a> {
         );
         let result: GoogleResponse<ListResponse<DefaultObjectAccessControl>> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -143,10 +142,7 @@ impl<'a> DefaultObjectAccessControlClient<'a> {
         );
         let result: GoogleResponse<DefaultObjectAccessControl> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -190,11 +186,13 @@ impl<'a> DefaultObjectAccessControlClient<'a> {
         );
         let result: GoogleResponse<DefaultObjectAccessControl> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(default_object_access_control)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(default_object_access_control),
+            )
             .await?
             .json()
             .await?;
@@ -237,15 +235,19 @@ impl<'a> DefaultObjectAccessControlClient<'a> {
         );
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
         }
     }
 }
@@ -0,0 +1,142 @@
+use futures_util::Stream;
+
+use crate::object::{CustomerEncryptionKey, ReadParameters, SizedByteStream};
+
+/// Configures the download of the content of a single object, which is started by one of the
+/// methods that return the content: [`bytes`](DownloadBuilder::bytes),
+/// [`response`](DownloadBuilder::response) or [`stream`](DownloadBuilder::stream).
+///
+/// A `DownloadBuilder` is obtained using [`ObjectClient::fetch`](super::ObjectClient::fetch).
+/// ### Example
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use cloud_storage::Client;
+///
+/// let client = Client::default();
+/// // the gzip compressed bytes, exactly as they were uploaded
+/// let compressed = client
+///     .object()
+///     .fetch("my_bucket", "logs/2024-01-01.txt")
+///     .raw(true)
+///     .bytes()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DownloadBuilder<'a> {
+    client: super::ObjectClient<'a>,
+    bucket: String,
+    name: String,
+    parameters: ReadParameters,
+    raw: Option<bool>,
+    encryption_key: Option<CustomerEncryptionKey>,
+}
+
+impl<'a> DownloadBuilder<'a> {
+    pub(super) fn new(client: super::ObjectClient<'a>, bucket: &str, name: &str) -> Self {
+        Self {
+            client,
+            bucket: bucket.to_string(),
+            name: name.to_string(),
+            parameters: ReadParameters::default(),
+            raw: None,
+            encryption_key: None,
+        }
+    }
+
+    /// Sets the `ReadParameters` of the download, for example to download a previous generation
+    /// of the object, or to download it only if it meets preconditions.
+    pub fn parameters(mut self, parameters: ReadParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Chooses how the content of objects that are stored with `Content-Encoding: gzip` is
+    /// returned. When `raw` is true, the content is returned as it is stored, so still compressed.
+    /// When `raw` is false, Google decompresses the content before sending it. This is done
+    /// through the `Accept-Encoding` header of the request, and has no effect on objects that are
+    /// not compressed, or that have `Cache-Control: no-transform` set, which are always returned
+    /// as they are stored.
+    ///
+    /// When this is not called, no `Accept-Encoding` header is sent, and Google decompresses the
+    /// content, unless the `reqwest::Client` of the client was configured to accept gzip.
+    pub fn raw(mut self, raw: bool) -> Self {
+        self.raw = Some(raw);
+        self
+    }
+
+    /// Sets the customer-supplied encryption key that the object was encrypted with. Without it,
+    /// Google refuses to return the content of such objects.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::CustomerEncryptionKey;
+    ///
+    /// let client = Client::default();
+    /// let key = CustomerEncryptionKey::from_base64(&std::env::var("ENCRYPTION_KEY")?)?;
+    /// let stream = client
+    ///     .object()
+    ///     .fetch("my_bucket", "backups/database.tar")
+    ///     .encryption_key(key)
+    ///     .stream()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encryption_key(mut self, encryption_key: CustomerEncryptionKey) -> Self {
+        self.encryption_key = Some(encryption_key);
+        self
+    }
+
+    /// Downloads the content of the object.
+    pub async fn bytes(self) -> crate::Result<Vec<u8>> {
+        let response = self.response().await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Starts downloading the content of the object, and returns the response once Google has
+    /// accepted the request, in the same way as `ObjectClient::download_response`.
+    pub async fn response(self) -> crate::Result<reqwest::Response> {
+        let accept_encoding = self.raw.map(accept_encoding);
+        self.client
+            .send_download(
+                &self.bucket,
+                &self.name,
+                &self.parameters,
+                accept_encoding,
+                self.encryption_key.as_ref(),
+            )
+            .await
+    }
+
+    /// Starts downloading the content of the object, and returns it as a stream of bytes, in the
+    /// same way as `ObjectClient::download_streamed`.
+    pub async fn stream(
+        self,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        let accept_encoding = self.raw.map(accept_encoding);
+        self.client
+            .send_download_streamed(
+                &self.bucket,
+                &self.name,
+                &self.parameters,
+                accept_encoding,
+                self.encryption_key.as_ref(),
+            )
+            .await
+    }
+}
+
+// The `Accept-Encoding` header that asks Google for the stored content when `raw` is true, and for
+// the decompressed content otherwise.
+fn accept_encoding(raw: bool) -> &'static str {
+    if raw {
+        "gzip"
+    } else {
+        "identity"
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     error::GoogleResponse,
-    hmac_key::{HmacKey, HmacMeta, HmacState},
+    hmac_key::{HmacKey, HmacListRequest, HmacMeta, HmacRotation, HmacState},
 };
 
 /// Operations on [`HmacKey`](HmacKey)s.
@@ -31,23 +31,53 @@ impl<'a> HmacKeyClient<'a> {
     /// # }
     /// ```
     pub async fn create(&self) -> crate::Result<HmacKey> {
+        let service_account = self.0.service_account();
+        self.create_for(&service_account.project_id, &service_account.client_email)
+            .await
+    }
+
+    /// Starts the rotation of the HMAC key with the id `access_id`, by creating a new key for the
+    /// same service account. The old key stays active, so that everything that uses it keeps
+    /// working while it is replaced by the new key. Once that is done, the old key should be
+    /// deactivated and deleted, as shown in the example.
+    ///
+    /// Google allows at most 10 HMAC keys per service account, counting both active and inactive
+    /// keys, so keys should not be left behind after a rotation.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::hmac_key::HmacState;
+    ///
+    /// let client = Client::default();
+    /// let rotation = client.hmac_key().rotate("old access id").await?;
+    /// // hand out `rotation.key.metadata.access_id` and `rotation.key.secret`, and wait until
+    /// // nothing uses the old key anymore
+    /// let old = &rotation.previous.access_id;
+    /// client.hmac_key().update(old, HmacState::Inactive).await?;
+    /// client.hmac_key().delete(old).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rotate(&self, access_id: &str) -> crate::Result<HmacRotation> {
+        let previous = self.read(access_id).await?;
+        let key = self
+            .create_for(&previous.project_id, &previous.service_account_email)
+            .await?;
+        Ok(HmacRotation { key, previous })
+    }
+
+    async fn create_for(&self, project_id: &str, email: &str) -> crate::Result<HmacKey> {
         use reqwest::header::CONTENT_LENGTH;
 
-        let url = format!(
-            "{}/projects/{}/hmacKeys",
-            crate::BASE_URL,
-            crate::SERVICE_ACCOUNT.project_id
-        );
-        let query = [("serviceAccountEmail", &crate::SERVICE_ACCOUNT.client_email)];
+        let url = format!("{}/projects/{}/hmacKeys", crate::BASE_URL, project_id);
+        let query = [("serviceAccountEmail", email)];
         let mut headers = self.0.get_headers().await?;
         headers.insert(CONTENT_LENGTH, 0.into());
         let result: GoogleResponse<HmacKey> = self
             .0
-            .client
-            .post(&url)
-            .headers(headers)
-            .query(&query)
-            .send()
+            .send(self.0.client.post(&url).headers(headers).query(&query))
             .await?
             .json()
             .await?;
@@ -79,17 +109,43 @@ impl<'a> HmacKeyClient<'a> {
     /// # }
     /// ```
     pub async fn list(&self) -> crate::Result<Vec<HmacMeta>> {
+        self.list_with(&HmacListRequest::default()).await
+    }
+
+    /// Retrieves a list of HMAC keys, in the same way as `list`, filtered by the provided
+    /// `HmacListRequest`. This can be used to only list the keys of a single service account, or to
+    /// include keys that have been deleted.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::hmac_key::HmacListRequest;
+    ///
+    /// let client = Client::default();
+    /// let list_request = HmacListRequest {
+    ///     service_account_email: Some("uploader@my-project.iam.gserviceaccount.com".to_string()),
+    ///     show_deleted_keys: Some(true),
+    /// };
+    /// let key_history = client.hmac_key().list_with(&list_request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_with(&self, list_request: &HmacListRequest) -> crate::Result<Vec<HmacMeta>> {
         let url = format!(
             "{}/projects/{}/hmacKeys",
             crate::BASE_URL,
-            crate::SERVICE_ACCOUNT.project_id
+            self.0.service_account().project_id
         );
         let response = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(list_request),
+            )
             .await?
             .text()
             .await?;
@@ -132,15 +188,12 @@ impl<'a> HmacKeyClient<'a> {
         let url = format!(
             "{}/projects/{}/hmacKeys/{}",
             crate::BASE_URL,
-            crate::SERVICE_ACCOUNT.project_id,
+            self.0.service_account().project_id,
             access_id
         );
         let result: GoogleResponse<HmacMeta> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
             .await?
             .json()
             .await?;
@@ -174,17 +227,19 @@ impl<'a> HmacKeyClient<'a> {
         let url = format!(
             "{}/projects/{}/hmacKeys/{}",
             crate::BASE_URL,
-            crate::SERVICE_ACCOUNT.project_id,
+            self.0.service_account().project_id,
             access_id
         );
         serde_json::to_string(&crate::hmac_key::UpdateMeta { state })?;
         let result: GoogleResponse<HmacMeta> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(&crate::hmac_key::UpdateMeta { state })
-            .send()
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(&crate::hmac_key::UpdateMeta { state }),
+            )
             .await?
             .json()
             .await?;
@@ -217,20 +272,24 @@ impl<'a> HmacKeyClient<'a> {
         let url = format!(
             "{}/projects/{}/hmacKeys/{}",
             crate::BASE_URL,
-            crate::SERVICE_ACCOUNT.project_id,
+            self.0.service_account().project_id,
             access_id
         );
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
         }
     }
 }
@@ -0,0 +1,304 @@
+use crate::{
+    bucket::{IamPolicy, TestIamPermission},
+    error::GoogleResponse,
+    managed_folder::{DeleteParameters, ListRequest, ManagedFolder, ManagedFolderList},
+    object::percent_encode,
+};
+
+/// Operations on [`ManagedFolder`](ManagedFolder)s.
+#[derive(Debug)]
+pub struct ManagedFolderClient<'a>(pub(super) &'a super::Client);
+
+impl<'a> ManagedFolderClient<'a> {
+    /// Creates a managed folder with the specified name in the specified bucket. The name must end
+    /// with a `/`. The folder does not need to contain any objects yet.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let folder = client.managed_folder().create("my-bucket", "reports/2024/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, bucket: &str, name: &str) -> crate::Result<ManagedFolder> {
+        let url = format!(
+            "{}/b/{}/managedFolders",
+            crate::BASE_URL,
+            percent_encode(bucket)
+        );
+        let result: GoogleResponse<ManagedFolder> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(&serde_json::json!({ "name": name })),
+            )
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the managed folder with the specified name in the specified bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let folder = client.managed_folder().read("my-bucket", "reports/2024/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read(&self, bucket: &str, name: &str) -> crate::Result<ManagedFolder> {
+        let url = folder_url(bucket, name);
+        let result: GoogleResponse<ManagedFolder> = self
+            .0
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns all managed folders in the specified bucket that match `list_request`. All pages of
+    /// results are requested before this returns.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::managed_folder::ListRequest;
+    ///
+    /// let client = Client::default();
+    /// let list_request = ListRequest {
+    ///     prefix: Some("reports/".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let folders = client.managed_folder().list("my-bucket", &list_request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        bucket: &str,
+        list_request: &ListRequest,
+    ) -> crate::Result<Vec<ManagedFolder>> {
+        let url = format!(
+            "{}/b/{}/managedFolders",
+            crate::BASE_URL,
+            percent_encode(bucket)
+        );
+        let mut folders = Vec::new();
+        let mut page_token = None;
+        loop {
+            let mut request = self
+                .0
+                .client
+                .get(&url)
+                .headers(self.0.get_headers().await?)
+                .query(list_request);
+            if let Some(ref page_token) = page_token {
+                request = request.query(&[("pageToken", page_token)]);
+            }
+            let result: GoogleResponse<ManagedFolderList> =
+                self.0.send(request).await?.json().await?;
+            let page = match result {
+                GoogleResponse::Success(s) => s,
+                GoogleResponse::Error(e) => return Err(e.into()),
+            };
+            folders.extend(page.items);
+            match page.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => return Ok(folders),
+            }
+        }
+    }
+
+    /// Deletes the managed folder with the specified name in the specified bucket. By default, only
+    /// empty managed folders can be deleted; see `DeleteParameters::allow_non_empty`.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::managed_folder::DeleteParameters;
+    ///
+    /// let client = Client::default();
+    /// client
+    ///     .managed_folder()
+    ///     .delete("my-bucket", "reports/2024/", &DeleteParameters::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(
+        &self,
+        bucket: &str,
+        name: &str,
+        parameters: &DeleteParameters,
+    ) -> crate::Result<()> {
+        let url = folder_url(bucket, name);
+        let response = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(parameters),
+            )
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
+        }
+    }
+
+    /// Returns the [IAM Policy](https://cloud.google.com/iam/docs/) of the managed folder with the
+    /// specified name in the specified bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let policy = client
+    ///     .managed_folder()
+    ///     .get_iam_policy("my-bucket", "reports/2024/")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_iam_policy(&self, bucket: &str, name: &str) -> crate::Result<IamPolicy> {
+        let url = format!("{}/iam", folder_url(bucket, name));
+        let result: GoogleResponse<IamPolicy> = self
+            .0
+            .send(self.0.client.get(&url).headers(self.0.get_headers().await?))
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Replaces the [IAM Policy](https://cloud.google.com/iam/docs/) of the managed folder with the
+    /// specified name in the specified bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket::{Binding, IamRole, StandardIamRole};
+    ///
+    /// let client = Client::default();
+    /// let mut policy = client
+    ///     .managed_folder()
+    ///     .get_iam_policy("my-bucket", "reports/2024/")
+    ///     .await?;
+    /// policy.bindings.push(Binding {
+    ///     role: IamRole::Standard(StandardIamRole::ObjectViewer),
+    ///     members: vec!["group:finance@example.com".to_string()],
+    ///     condition: None,
+    /// });
+    /// client
+    ///     .managed_folder()
+    ///     .set_iam_policy("my-bucket", "reports/2024/", &policy)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_iam_policy(
+        &self,
+        bucket: &str,
+        name: &str,
+        iam: &IamPolicy,
+    ) -> crate::Result<IamPolicy> {
+        let url = format!("{}/iam", folder_url(bucket, name));
+        let result: GoogleResponse<IamPolicy> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .json(iam),
+            )
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Checks whether the user provided in the service account has `permission` on the managed
+    /// folder with the specified name in the specified bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// client
+    ///     .managed_folder()
+    ///     .test_iam_permission("my-bucket", "reports/2024/", "storage.objects.get")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_iam_permission(
+        &self,
+        bucket: &str,
+        name: &str,
+        permission: &str,
+    ) -> crate::Result<TestIamPermission> {
+        let url = format!("{}/iam/testPermissions", folder_url(bucket, name));
+        let result: GoogleResponse<TestIamPermission> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&[("permissions", permission)]),
+            )
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+}
+
+// The url of a managed folder, whose name is encoded as a single path segment.
+fn folder_url(bucket: &str, name: &str) -> String {
+    format!(
+        "{}/b/{}/managedFolders/{}",
+        crate::BASE_URL,
+        percent_encode(bucket),
+        percent_encode(name),
+    )
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::Object;
+
+// The metadata of recently read objects, see `ClientBuilder::metadata_cache`. Entries are keyed
+// by bucket, object name and the generation that was requested, where `None` stands for the live
+// version. They are evicted once they are older than `ttl`, or when room is
+// needed for a new entry, oldest first.
+pub(super) struct MetadataCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<Key, (Instant, Object)>>,
+}
+
+type Key = (String, String, Option<i64>);
+
+impl fmt::Debug for MetadataCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MetadataCache")
+            .field("ttl", &self.ttl)
+            .field("max_entries", &self.max_entries)
+            .finish()
+    }
+}
+
+impl MetadataCache {
+    pub(super) fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(super) fn get(&self, bucket: &str, name: &str, generation: Option<i64>) -> Option<Object> {
+        let mut entries = self.entries.lock().unwrap();
+        let key = (bucket.to_string(), name.to_string(), generation);
+        match entries.get(&key) {
+            Some((stored, object)) if stored.elapsed() < self.ttl => Some(object.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn insert(&self, object: &Object, generation: Option<i64>) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        let key = (object.bucket.clone(), object.name.clone(), generation);
+        if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+            entries.retain(|_, (stored, _)| stored.elapsed() < self.ttl);
+        }
+        if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (stored, _))| *stored)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key, (Instant::now(), object.clone()));
+    }
+
+    pub(super) fn invalidate(&self, bucket: &str, name: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|(b, n, _), _| b != bucket || n != name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_and_invalidates() {
+        let cache = MetadataCache::new(Duration::from_secs(60), 2);
+        for name in ["a", "b", "c"] {
+            cache.insert(&crate::test_object("my-bucket", name), None);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(cache.get("my-bucket", "a", None).is_none());
+        assert_eq!(
+            cache.get("my-bucket", "c", None),
+            Some(crate::test_object("my-bucket", "c"))
+        );
+        assert!(cache.get("my-bucket", "c", Some(1)).is_none());
+
+        cache.insert(&crate::test_object("my-bucket", "c"), Some(1));
+        cache.invalidate("my-bucket", "c");
+        assert!(cache.get("my-bucket", "c", None).is_none());
+        assert!(cache.get("my-bucket", "c", Some(1)).is_none());
+
+        let expired = MetadataCache::new(Duration::ZERO, 2);
+        expired.insert(&crate::test_object("my-bucket", "a"), None);
+        assert!(expired.get("my-bucket", "a", None).is_none());
+    }
+}
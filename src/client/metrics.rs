@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+/// Receives a `RequestMetrics` for every http request that a `Client` sends to Google Cloud
+/// Storage, which can be used to feed counters and histograms of a metrics system. It is
+/// configured using `ClientBuilder::metrics_sink`.
+///
+/// `record` is called on the task that performs the request, right after the headers of the
+/// response were received, so it should return quickly.
+/// ### Example
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use cloud_storage::client::{MetricsSink, RequestMetrics};
+/// use cloud_storage::Client;
+///
+/// struct Log;
+///
+/// impl MetricsSink for Log {
+///     fn record(&self, metrics: &RequestMetrics) {
+///         println!(
+///             "{} {} returned {:?} after {:?}",
+///             metrics.method, metrics.route, metrics.status, metrics.latency
+///         );
+///     }
+/// }
+///
+/// let client = Client::builder().metrics_sink(Log).build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// Records the outcome of a single request.
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+/// The measurements of a single request, passed to a `MetricsSink`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestMetrics {
+    /// The http method of the request.
+    pub method: reqwest::Method,
+    /// The route of the url that was requested, which identifies the kind of operation. This is the
+    /// path of the url with the names of buckets, objects and other resources replaced by
+    /// placeholders, for example `/storage/v1/b/{bucket}/o/{object}`, so it can be used as the
+    /// label of a metric.
+    pub route: String,
+    /// The status of the response, or `None` when no response was received, for example because
+    /// the connection failed or the request timed out.
+    pub status: Option<reqwest::StatusCode>,
+    /// The time between sending the request and receiving the headers of the response.
+    pub latency: Duration,
+    /// The size of the request body in bytes, when it was known up front. Streamed bodies are not
+    /// counted.
+    pub request_bytes: Option<u64>,
+    /// The size of the response body in bytes, as announced by the `Content-Length` header. This is
+    /// `None` when the size is not known up front, for example for chunked or compressed
+    /// responses.
+    pub response_bytes: Option<u64>,
+}
+
+// The segments of a url path that are followed by the name of a resource, and the placeholder that
+// replaces that name in a route.
+const NAMED_SEGMENTS: [(&str, &str); 8] = [
+    ("b", "{bucket}"),
+    ("o", "{object}"),
+    ("acl", "{entity}"),
+    ("defaultObjectAcl", "{entity}"),
+    ("managedFolders", "{managed_folder}"),
+    ("notificationConfigs", "{notification}"),
+    ("projects", "{project}"),
+    ("hmacKeys", "{access_id}"),
+];
+
+// The route of `path`, see `RequestMetrics::route`.
+pub(crate) fn route(path: &str) -> String {
+    let mut placeholder = None;
+    let mut route = String::with_capacity(path.len());
+    for segment in path.split('/').skip(1) {
+        route.push('/');
+        match placeholder.take() {
+            Some(placeholder) => route.push_str(placeholder),
+            None => {
+                route.push_str(segment);
+                placeholder = NAMED_SEGMENTS
+                    .iter()
+                    .find(|(name, _)| *name == segment)
+                    .map(|(_, placeholder)| *placeholder);
+            }
+        }
+    }
+    route
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_left_out_of_routes() {
+        assert_eq!(route("/storage/v1/b"), "/storage/v1/b");
+        assert_eq!(route("/storage/v1/b/o/o"), "/storage/v1/b/{bucket}/o");
+        assert_eq!(
+            route("/storage/v1/b/my-bucket/o/reports%2F2024.pdf/acl/allUsers"),
+            "/storage/v1/b/{bucket}/o/{object}/acl/{entity}"
+        );
+        assert_eq!(
+            route("/storage/v1/b/a/o/b/rewriteTo/b/c/o/d"),
+            "/storage/v1/b/{bucket}/o/{object}/rewriteTo/b/{bucket}/o/{object}"
+        );
+        assert_eq!(
+            route("/upload/storage/v1/b/my-bucket/o"),
+            "/upload/storage/v1/b/{bucket}/o"
+        );
+        assert_eq!(
+            route("/storage/v1/projects/my-project/hmacKeys/GOOG1EXAMPLE"),
+            "/storage/v1/projects/{project}/hmacKeys/{access_id}"
+        );
+    }
+}
@@ -1,15 +1,42 @@
 use futures_util::{stream, Stream, TryStream};
 use reqwest::StatusCode;
 
+use super::{DownloadBuilder, RangeReader, UploadBuilder};
 use crate::{
     error::GoogleResponse,
-    object::{percent_encode, ComposeRequest, ObjectList, RewriteResponse, SizedByteStream},
-    ListRequest, Object,
+    object::{
+        percent_encode, ComposeParameters, ComposeRequest, CopyParameters, CustomerEncryptionKey,
+        ListEntry, ObjectHashes, ObjectList, ObjectRetention, Preconditions, PredefinedAcl,
+        ReadParameters, RewriteParameters, RewriteResponse, SizedByteStream, SourceObject, Usage,
+    },
+    ListRequest, Object, UploadOptions,
 };
 
 // Object uploads has its own url for some reason
 const BASE_URL: &str = "https://storage.googleapis.com/upload/storage/v1/b";
 
+// Separates the metadata from the contents in multipart uploads
+const MULTIPART_BOUNDARY: &str = "cloud_storage_rs_multipart_boundary_5e3c2a7d";
+
+// The maximum number of source objects Google accepts in a single compose request
+const MAX_COMPOSE_SOURCES: usize = 32;
+
+// The size of the chunks in which a resumable upload is sent. Google requires every chunk but the
+// last to be a multiple of 256 KiB.
+const RESUMABLE_CHUNK_SIZE: usize = 32 * 256 * 1024;
+
+// How often a chunk is retried when a streamed upload of unknown length is sent as a resumable
+// upload
+const UNKNOWN_LENGTH_RETRIES: u32 = 3;
+
+// The shortest time that `wait_for` waits between two attempts, so that a zero poll interval does
+// not send requests in a tight loop
+const MIN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+// The longest time that the backoff of `wait_for` grows to, unless a longer poll interval was
+// requested
+const MAX_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// Operations on [`Object`](Object)s.
 #[derive(Debug)]
 pub struct ObjectClient<'a>(pub(super) &'a super::Client);
@@ -18,6 +45,12 @@ impl<'a> ObjectClient<'a> {
     /// Create a new object.
     /// Upload a file as that is loaded in memory to google cloud storage, where it will be
     /// interpreted according to the mime type you specified.
+    ///
+    /// The `generation` of the returned object is the generation that this upload wrote, even
+    /// when other clients write the same name concurrently. It can be used as the
+    /// `if_generation_match` precondition of later operations, so that they only apply to exactly
+    /// this version of the object. Every write to a name results in a greater generation than the
+    /// previous one. The same holds for the other methods that create objects.
     /// ## Example
     /// ```rust,no_run
     /// # #[tokio::main]
@@ -39,34 +72,221 @@ impl<'a> ObjectClient<'a> {
         filename: &str,
         mime_type: &str,
     ) -> crate::Result<Object> {
+        self.create_with(bucket, file, filename, mime_type, UploadOptions::default())
+            .await
+    }
+
+    /// Creates a new object in the same way as `create`, and returns it together with a
+    /// [Signed Url](https://cloud.google.com/storage/docs/access-control/signed-urls) that lets
+    /// anyone download it without authentication for `duration` seconds. The url is signed by the
+    /// service account that the client operates as.
+    ///
+    /// `duration` may not be greater than a week. This is checked before the object is uploaded.
+    /// ## Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let report = b"quarterly numbers".to_vec();
+    /// let client = Client::default();
+    /// let (object, url) = client
+    ///     .object()
+    ///     .create_and_sign("reports", report, "q3.txt", "text/plain", 3600)
+    ///     .await?;
+    /// // `url` can be shared, and is valid for an hour.
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_and_sign(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+        duration: u32,
+    ) -> crate::Result<(Object, String)> {
+        if duration > 604800 {
+            return Err(crate::Error::Other(format!(
+                "duration may not be greater than 604800, but was {}",
+                duration
+            )));
+        }
+        let object = self.create(bucket, file, filename, mime_type).await?;
+        let url = object.download_url_as(duration, self.0.service_account())?;
+        Ok((object, url))
+    }
+
+    /// Create a new object, using the provided `UploadOptions`. These can be used to compress the
+    /// file before it is uploaded.
+    /// ## Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{Client, UploadOptions};
+    ///
+    /// let logs = b"a lot of repetitive log lines".to_vec();
+    /// let client = Client::default();
+    /// let options = UploadOptions::new().gzip(true);
+    /// let object = client
+    ///     .object()
+    ///     .create_with("logs", logs, "today.log", "text/plain", options)
+    ///     .await?;
+    /// assert_eq!(object.content_encoding.as_deref(), Some("gzip"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_with(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+        options: UploadOptions,
+    ) -> crate::Result<Object> {
+        let response = self
+            .send_upload(
+                bucket,
+                file,
+                filename,
+                mime_type,
+                options,
+                &Preconditions::default(),
+            )
+            .await?;
+        if response.status() == 200 {
+            let object: Object = serde_json::from_str(&response.text().await?)?;
+            self.forget(&object.bucket, &object.name);
+            Ok(object)
+        } else {
+            Err(crate::Error::new(&response.text().await?))
+        }
+    }
+
+    /// Create a new object, but only if no object with the same name exists yet in the bucket.
+    /// Returns `None` if the object already exists, in which case it is left untouched. This
+    /// makes it possible to claim a name safely, even when multiple processes race to create it.
+    /// ## Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let lock = b"worker-7".to_vec();
+    /// match client.object().create_if_absent("jobs", lock, "locks/nightly", "text/plain").await? {
+    ///     Some(_) => println!("acquired the lock"),
+    ///     None => println!("another worker holds the lock"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_if_absent(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+    ) -> crate::Result<Option<Object>> {
+        let preconditions = Preconditions {
+            if_generation_match: Some(0),
+            ..Default::default()
+        };
+        let response = self
+            .send_upload(
+                bucket,
+                file,
+                filename,
+                mime_type,
+                UploadOptions::default(),
+                &preconditions,
+            )
+            .await?;
+        match response.status() {
+            StatusCode::OK => {
+                let object: Object = serde_json::from_str(&response.text().await?)?;
+                self.forget(&object.bucket, &object.name);
+                Ok(Some(object))
+            }
+            StatusCode::PRECONDITION_FAILED => Ok(None),
+            _ => Err(crate::Error::new(&response.text().await?)),
+        }
+    }
+
+    // Sends the upload of an object that is loaded in memory, see `create_with`.
+    async fn send_upload(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+        options: UploadOptions,
+        preconditions: &Preconditions,
+    ) -> crate::Result<reqwest::Response> {
         use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
 
-        let url = &format!(
-            "{}/{}/o?uploadType=media&name={}",
+        // default metadata can only be sent along in a multipart upload
+        let upload_type = if self.0.default_metadata.is_empty() {
+            "media"
+        } else {
+            "multipart"
+        };
+        let filename = &self.0.object_name_policy.apply(filename)?;
+        let mut url = format!(
+            "{}/{}/o?uploadType={}&name={}",
             BASE_URL,
             percent_encode(bucket),
+            upload_type,
             percent_encode(filename),
         );
+        let file = if options.gzip {
+            // The `contentEncoding` parameter sets the metadata of the stored object. Sending a
+            // `Content-Encoding` header instead would make Google decompress the request body and
+            // store the uncompressed data.
+            url.push_str("&contentEncoding=gzip");
+            gzip(&file)?
+        } else {
+            file
+        };
+        let url = &url;
         let mut headers = self.0.get_headers().await?;
-        headers.insert(CONTENT_TYPE, mime_type.parse()?);
+        headers.extend(options.headers()?);
+        let file = if self.0.default_metadata.is_empty() {
+            headers.insert(CONTENT_TYPE, mime_type.parse()?);
+            file
+        } else {
+            let mut metadata = serde_json::json!({});
+            if options.gzip {
+                metadata["contentEncoding"] = "gzip".into();
+            }
+            let metadata = self.0.upload_metadata(&metadata);
+            let (head, tail) = multipart_delimiters(&metadata, mime_type)?;
+            headers.insert(CONTENT_TYPE, multipart_content_type().parse()?);
+            [head.as_bytes(), &file, tail.as_bytes()].concat()
+        };
         headers.insert(CONTENT_LENGTH, file.len().to_string().parse()?);
-        let response = self
+        let request = self
             .0
             .client
             .post(url)
             .headers(headers)
-            .body(file)
-            .send()
-            .await?;
-        if response.status() == 200 {
-            Ok(serde_json::from_str(&response.text().await?)?)
-        } else {
-            Err(crate::Error::new(&response.text().await?))
-        }
+            .query(preconditions)
+            .query(&options.query())
+            .body(file);
+        Ok(self.0.send(request).await?)
     }
 
     /// Create a new object. This works in the same way as `ObjectClient::create`, except it does not need
     /// to load the entire file in ram.
+    ///
+    /// If `stream` yields an error, or yields fewer bytes than `length`, an error is returned. When
+    /// Google has already stored the incomplete upload as an object, that object is deleted again,
+    /// and if that fails too, the returned error mentions the generation of the partial object.
+    ///
+    /// When `length` is `None`, the content is sent through a resumable upload session, as
+    /// described for `ObjectClient::upload_resumable`, rather than as a single request with
+    /// chunked transfer encoding, which some proxies do not handle. Each chunk of the session has
+    /// a known length, and the object is only created once all of the content has arrived.
     /// ## Example
     /// ```rust,no_run
     /// # #[tokio::main]
@@ -97,8 +317,19 @@ impl<'a> ObjectClient<'a> {
         S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
         bytes::Bytes: From<S::Ok>,
     {
+        use futures_util::{StreamExt, TryStreamExt};
         use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
 
+        let length = length.into();
+        // default metadata can only be sent along in a multipart or resumable upload
+        if !self.0.default_metadata.is_empty() || length.is_none() {
+            let metadata = serde_json::json!({});
+            return self
+                .create_streamed_with(bucket, stream, length, filename, mime_type, &metadata)
+                .await;
+        }
+
+        let filename = &self.0.object_name_policy.apply(filename)?;
         let url = &format!(
             "{}/{}/o?uploadType=media&name={}",
             BASE_URL,
@@ -107,159 +338,924 @@ impl<'a> ObjectClient<'a> {
         );
         let mut headers = self.0.get_headers().await?;
         headers.insert(CONTENT_TYPE, mime_type.parse()?);
-        if let Some(length) = length.into() {
+        if let Some(length) = length {
             headers.insert(CONTENT_LENGTH, length.into());
         }
 
-        let body = reqwest::Body::wrap_stream(stream);
+        let progress = std::sync::Arc::new(UploadProgress::default());
+        let content = stream
+            .into_stream()
+            .map(|chunk| chunk.map(bytes::Bytes::from).map_err(Into::into));
+        let body = media_body(track_upload(content, progress.clone()));
         let response = self
             .0
-            .client
-            .post(url)
-            .headers(headers)
-            .body(body)
-            .send()
+            .send(self.0.client.post(url).headers(headers).body(body))
             .await?;
         if response.status() == 200 {
-            Ok(serde_json::from_str(&response.text().await?)?)
+            let object = serde_json::from_str(&response.text().await?)?;
+            self.check_upload(object, &progress, length).await
         } else {
             Err(crate::Error::new(&response.text().await?))
         }
     }
 
-    /// Obtain a list of objects within this Bucket.
-    /// ### Example
-    /// ```no_run
+    /// Create a new object with the provided metadata, streaming its contents. This works in the
+    /// same way as `ObjectClient::create_streamed`, except that `metadata` is sent along in the same
+    /// request. `metadata` is a (partial) object resource, so to set custom metadata it should be
+    /// nested under the `metadata` key, as shown in the example below. Likewise, access controls
+    /// for the new object go under the `acl` key; [`ObjectClient::upload`] offers a typed way to
+    /// set them. Incomplete uploads are handled as described for `ObjectClient::create_streamed`.
+    ///
+    /// When `length` is known, the metadata and the content are sent in a single multipart request
+    /// whose `Content-Length` covers both parts, so no chunked transfer encoding is used. When it
+    /// is `None`, the metadata is sent when starting a resumable upload session instead.
+    /// ## Example
+    /// ```rust,no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use cloud_storage::Client;
-    /// use cloud_storage::{Object, ListRequest};
     ///
     /// let client = Client::default();
-    /// let all_objects = client.object().list("my_bucket", ListRequest::default()).await?;
+    /// let file = reqwest::Client::new()
+    ///     .get("https://my_domain.rs/nice_cat_photo.png")
+    ///     .send()
+    ///     .await?
+    ///     .bytes_stream();
+    /// let metadata = serde_json::json!({
+    ///     "cacheControl": "public, max-age=3600",
+    ///     "metadata": { "photographer": "Alice" },
+    /// });
+    /// client
+    ///     .object()
+    ///     .create_streamed_with("cat-photos", file, 10, "recently read cat.png", "image/png", &metadata)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(
+    pub async fn create_streamed_with<S>(
         &self,
-        bucket: &'a str,
-        list_request: ListRequest,
-    ) -> crate::Result<impl Stream<Item = crate::Result<ObjectList>> + 'a> {
-        enum ListState {
-            Start(ListRequest),
-            HasMore(ListRequest),
-            Done,
-        }
-        use ListState::*;
-        impl ListState {
-            fn into_has_more(self) -> Option<ListState> {
-                match self {
-                    Start(req) | HasMore(req) => Some(HasMore(req)),
-                    Done => None,
-                }
-            }
-
-            fn req_mut(&mut self) -> Option<&mut ListRequest> {
-                match self {
-                    Start(ref mut req) | HasMore(ref mut req) => Some(req),
-                    Done => None,
-                }
-            }
-        }
-
-        let client = self.0;
-
-        Ok(stream::unfold(
-            ListState::Start(list_request),
-            move |mut state| async move {
-                let url = format!("{}/b/{}/o", crate::BASE_URL, percent_encode(bucket));
-                let headers = match client.get_headers().await {
-                    Ok(h) => h,
-                    Err(e) => return Some((Err(e), state)),
-                };
-                let req = state.req_mut()?;
-                if req.max_results == Some(0) {
-                    return None;
-                }
-
-                let response = client
-                    .client
-                    .get(&url)
-                    .query(req)
-                    .headers(headers)
-                    .send()
-                    .await;
+        bucket: &str,
+        stream: S,
+        length: impl Into<Option<u64>>,
+        filename: &str,
+        mime_type: &str,
+        metadata: &serde_json::Value,
+    ) -> crate::Result<Object>
+    where
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        bytes::Bytes: From<S::Ok>,
+    {
+        self.upload_multipart(
+            bucket,
+            stream,
+            length.into(),
+            filename,
+            mime_type,
+            metadata,
+            None,
+            &UploadOptions::default(),
+        )
+        .await
+    }
 
-                let response = match response {
-                    Ok(r) if r.status() == 200 => r,
-                    Ok(r) => {
-                        let e = match r.json::<crate::error::GoogleErrorResponse>().await {
-                            Ok(err_res) => err_res.into(),
-                            Err(serde_err) => serde_err.into(),
-                        };
-                        return Some((Err(e), state));
-                    }
-                    Err(e) => return Some((Err(e.into()), state)),
-                };
+    // Uploads `stream` along with `metadata` in a single multipart request, or in a resumable
+    // upload when its length is unknown, see `create_streamed_with`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn upload_multipart<S>(
+        &self,
+        bucket: &str,
+        stream: S,
+        length: Option<u64>,
+        filename: &str,
+        mime_type: &str,
+        metadata: &serde_json::Value,
+        predefined_acl: Option<PredefinedAcl>,
+        options: &UploadOptions,
+    ) -> crate::Result<Object>
+    where
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        bytes::Bytes: From<S::Ok>,
+    {
+        use futures_util::{StreamExt, TryStreamExt};
+        use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
 
-                let result: GoogleResponse<ObjectList> = match response.json().await {
-                    Ok(json) => json,
-                    Err(e) => return Some((Err(e.into()), state)),
-                };
+        if length.is_none() {
+            let session = self
+                .start_session(
+                    bucket,
+                    filename,
+                    mime_type,
+                    None,
+                    metadata,
+                    predefined_acl,
+                    options,
+                )
+                .await?;
+            let content = stream
+                .into_stream()
+                .map(|chunk| chunk.map(bytes::Bytes::from).map_err(Into::into));
+            let upload = ResumableUpload {
+                client: &self.0.client,
+                metrics_sink: self.0.metrics_sink.as_deref(),
+                session_uri: &session,
+                headers: options.headers()?,
+                length: None,
+                retries_left: UNKNOWN_LENGTH_RETRIES,
+            };
+            let object = upload.send(Box::pin(content), RESUMABLE_CHUNK_SIZE).await?;
+            self.forget(&object.bucket, &object.name);
+            return Ok(object);
+        }
 
-                let response_body = match result {
-                    GoogleResponse::Success(success) => success,
-                    GoogleResponse::Error(e) => return Some((Err(e.into()), state)),
-                };
+        let filename = &self.0.object_name_policy.apply(filename)?;
+        let url = &format!(
+            "{}/{}/o?uploadType=multipart&name={}",
+            BASE_URL,
+            percent_encode(bucket),
+            percent_encode(filename),
+        );
+        let metadata = self.0.upload_metadata(metadata);
+        let (head, tail) = multipart_delimiters(&metadata, mime_type)?;
 
-                let next_state = if let Some(ref page_token) = response_body.next_page_token {
-                    req.page_token = Some(page_token.clone());
-                    req.max_results = req
-                        .max_results
-                        .map(|rem| rem.saturating_sub(response_body.items.len()));
-                    state.into_has_more()?
-                } else {
-                    Done
-                };
+        let mut headers = self.0.get_headers().await?;
+        headers.extend(options.headers()?);
+        headers.insert(CONTENT_TYPE, multipart_content_type().parse()?);
+        if let Some(length) = length {
+            headers.insert(
+                CONTENT_LENGTH,
+                multipart_length(&head, length, &tail).into(),
+            );
+        }
 
-                Some((Ok(response_body), next_state))
-            },
-        ))
+        let progress = std::sync::Arc::new(UploadProgress::default());
+        let content = stream
+            .into_stream()
+            .map(|chunk| chunk.map(bytes::Bytes::from).map_err(Into::into));
+        let content = track_upload(content, progress.clone());
+        let mut request = self.0.client.post(url).headers(headers);
+        if let Some(predefined_acl) = predefined_acl {
+            request = request.query(&[("predefinedAcl", predefined_acl)]);
+        }
+        let request = request.query(&options.query());
+        let response = self
+            .0
+            .send(request.body(multipart_body(head, content, tail)))
+            .await?;
+        if response.status() == 200 {
+            let object = serde_json::from_str(&response.text().await?)?;
+            self.check_upload(object, &progress, length).await
+        } else {
+            Err(crate::Error::new(&response.text().await?))
+        }
     }
 
-    /// Obtains a single object with the specified name in the specified bucket.
+    /// Create a new object from the contents of the local file at `path`, without loading the
+    /// entire file in ram. The file is streamed in the same way as for
+    /// `ObjectClient::create_streamed`, with the length of the file as the length of the content.
     /// ### Example
     /// ```no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use cloud_storage::Client;
-    /// use cloud_storage::Object;
     ///
     /// let client = Client::default();
-    /// let object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// client
+    ///     .object()
+    ///     .create_from_file("cat-photos", "photos/cat.png", "cat.png", "image/png")
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn read(&self, bucket: &str, file_name: &str) -> crate::Result<Object> {
-        let url = format!(
-            "{}/b/{}/o/{}",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(file_name),
-        );
-        let result: GoogleResponse<Object> = self
-            .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
-            .await?
-            .json()
-            .await?;
-        match result {
-            GoogleResponse::Success(s) => Ok(s),
-            GoogleResponse::Error(e) => Err(e.into()),
-        }
+    pub async fn create_from_file(
+        &self,
+        bucket: &str,
+        path: impl AsRef<std::path::Path>,
+        filename: &str,
+        mime_type: &str,
+    ) -> crate::Result<Object> {
+        let file = tokio::fs::File::open(path).await?;
+        let length = file.metadata().await?.len();
+        self.create_from_reader(bucket, file, length, filename, mime_type)
+            .await
+    }
+
+    /// Create a new object with the contents that are read from `reader`. This works in the same
+    /// way as `ObjectClient::create_streamed`, and takes care of turning the reader into a stream.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let file = tokio::fs::File::open("backup.tar").await?;
+    /// let length = file.metadata().await?.len();
+    /// client
+    ///     .object()
+    ///     .create_from_reader("my_bucket", file, length, "backup.tar", "application/x-tar")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_from_reader<R>(
+        &self,
+        bucket: &str,
+        reader: R,
+        length: impl Into<Option<u64>>,
+        filename: &str,
+        mime_type: &str,
+    ) -> crate::Result<Object>
+    where
+        R: tokio::io::AsyncRead + Send + Sync + 'static,
+    {
+        let stream = tokio_util::io::ReaderStream::new(reader);
+        self.create_streamed(bucket, stream, length, filename, mime_type)
+            .await
+    }
+
+    /// Starts a resumable upload of an object with the specified name in the specified bucket, and
+    /// returns the URI of the upload session. The content is sent afterwards using
+    /// `ObjectClient::upload_resumable`. `metadata` is a (partial) object resource, in the same way
+    /// as for `ObjectClient::create_streamed_with`.
+    ///
+    /// The mime type, and the length if it is known, are declared up front, so Google can reject an
+    /// upload that is too large or of the wrong type before any content is sent. The session URI
+    /// stays valid for a week, and authorizes anyone who has it to upload to the session.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let content = tokio::fs::read("backup.tar").await?;
+    /// let length = content.len() as u64;
+    /// let session = client
+    ///     .object()
+    ///     .start_resumable_upload("my_bucket", "backup.tar", "application/x-tar", length, &serde_json::json!({}))
+    ///     .await?;
+    /// let stream = futures_util::stream::once(async { Ok::<_, std::io::Error>(content) });
+    /// client.object().upload_resumable(&session, stream, length, 3).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn start_resumable_upload(
+        &self,
+        bucket: &str,
+        filename: &str,
+        mime_type: &str,
+        length: impl Into<Option<u64>>,
+        metadata: &serde_json::Value,
+    ) -> crate::Result<String> {
+        self.start_session(
+            bucket,
+            filename,
+            mime_type,
+            length.into(),
+            metadata,
+            None,
+            &UploadOptions::default(),
+        )
+        .await
+    }
+
+    // Starts a resumable upload session, see `start_resumable_upload`.
+    #[allow(clippy::too_many_arguments)]
+    async fn start_session(
+        &self,
+        bucket: &str,
+        filename: &str,
+        mime_type: &str,
+        length: Option<u64>,
+        metadata: &serde_json::Value,
+        predefined_acl: Option<PredefinedAcl>,
+        options: &UploadOptions,
+    ) -> crate::Result<String> {
+        let filename = &self.0.object_name_policy.apply(filename)?;
+        let url = &format!(
+            "{}/{}/o?uploadType=resumable&name={}",
+            BASE_URL,
+            percent_encode(bucket),
+            percent_encode(filename),
+        );
+        let mut headers = self.0.get_headers().await?;
+        headers.extend(resumable_session_headers(mime_type, length)?);
+        headers.extend(options.headers()?);
+        let body = serde_json::to_vec(&self.0.upload_metadata(metadata))?;
+        let mut request = self.0.client.post(url).headers(headers);
+        if let Some(predefined_acl) = predefined_acl {
+            request = request.query(&[("predefinedAcl", predefined_acl)]);
+        }
+        let request = request.query(&options.query());
+        let response = self.0.send(request.body(body)).await?;
+        if !response.status().is_success() {
+            return Err(crate::Error::new(&response.text().await?));
+        }
+        match response.headers().get(reqwest::header::LOCATION) {
+            Some(location) => Ok(location.to_str()?.to_string()),
+            None => Err(crate::Error::new(
+                "the response to the start of a resumable upload has no session URI",
+            )),
+        }
+    }
+
+    /// Sends the content of a resumable upload that was started with
+    /// `ObjectClient::start_resumable_upload`, and returns the created object. If `length` was
+    /// declared when the session was started, the same `length` must be passed here.
+    ///
+    /// Unlike the other streamed uploads, an object is only created once all of its content has
+    /// been received, so a stream that fails halfway leaves no partial object behind.
+    ///
+    /// The content is sent in chunks of 8 MiB. When sending a chunk fails because of the
+    /// connection or a transient error on Google's side, the session is asked how much of the
+    /// content it has received, and the upload continues from there, at most `max_retries` times
+    /// in total. Up to one chunk of the content is kept in memory for this.
+    ///
+    /// ### Cancellation
+    /// Dropping the returned future aborts the request that is in flight, but leaves the session
+    /// intact. It can be continued by calling this method again with the complete content, in
+    /// which case the bytes that the session has already received are skipped instead of sent
+    /// again. A session that is not going to be continued should be cancelled with
+    /// `abort_resumable_session`.
+    pub async fn upload_resumable<S>(
+        &self,
+        session_uri: &str,
+        stream: S,
+        length: impl Into<Option<u64>>,
+        max_retries: u32,
+    ) -> crate::Result<Object>
+    where
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        bytes::Bytes: From<S::Ok>,
+    {
+        use futures_util::{StreamExt, TryStreamExt};
+
+        let content = stream
+            .into_stream()
+            .map(|chunk| chunk.map(bytes::Bytes::from).map_err(Into::into));
+        let upload = ResumableUpload {
+            client: &self.0.client,
+            metrics_sink: self.0.metrics_sink.as_deref(),
+            session_uri,
+            headers: reqwest::header::HeaderMap::new(),
+            length: length.into(),
+            retries_left: max_retries,
+        };
+        let object = upload.send(Box::pin(content), RESUMABLE_CHUNK_SIZE).await?;
+        self.forget(&object.bucket, &object.name);
+        Ok(object)
+    }
+
+    /// Cancels a resumable upload session that was started with `start_resumable_upload`, for
+    /// example after an upload to it was cancelled by dropping it. The content that the session
+    /// has received is discarded, no object is created, and the session URI can no longer be
+    /// used. Cancelling a session that was already cancelled succeeds as well.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let session = client
+    ///     .object()
+    ///     .start_resumable_upload("my_bucket", "backup.tar", "application/x-tar", None, &serde_json::json!({}))
+    ///     .await?;
+    /// // the user changed their mind before the upload completed
+    /// client.object().abort_resumable_session(&session).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_resumable_session(&self, session_uri: &str) -> crate::Result<()> {
+        // the session URI itself authorizes the request
+        let response = self.0.send(self.0.client.delete(session_uri)).await?;
+        match response.status().as_u16() {
+            // Google answers a cancelled session with 499 Client Closed Request
+            499 => Ok(()),
+            // the session had been cancelled before, or has expired
+            404 | 410 => Ok(()),
+            _ if response.status().is_success() => Ok(()),
+            status => Err(crate::Error::Other(format!(
+                "cancelling the upload session failed with status {}: {}",
+                status,
+                response.text().await?
+            ))),
+        }
+    }
+
+    /// Returns an `UploadBuilder` to create an object with the specified name in the specified
+    /// bucket, which can set the content type, content headers, custom metadata, storage class
+    /// and access controls of the object in a single upload.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .upload("my_bucket", "index.html")
+    ///     .content_type("text/html")
+    ///     .cache_control("public, max-age=3600")
+    ///     .bytes("<html></html>")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload(&self, bucket: &str, filename: &str) -> UploadBuilder<'a> {
+        UploadBuilder::new(ObjectClient(self.0), bucket, filename)
+    }
+
+    /// Uploads all files in `local_dir` and its subdirectories to the specified bucket. The path of
+    /// each file relative to `local_dir`, with `/` as separator, is used as the name of its object,
+    /// preceded by `prefix`. The mime type of each file is inferred from its extension. At most
+    /// `concurrency` files are uploaded at the same time, and each of them is streamed from disk
+    /// rather than loaded in ram. Symbolic links to directories are not followed.
+    ///
+    /// An error is returned if the directory can not be read. Otherwise, the result of each upload
+    /// is returned along with the path of the file, ordered by path, so one failing upload does
+    /// not hide the others.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let results = client
+    ///     .object()
+    ///     .upload_dir("public", "my_bucket", "site/", 8)
+    ///     .await?;
+    /// for (path, result) in results {
+    ///     if let Err(e) = result {
+    ///         eprintln!("failed to upload {}: {}", path.display(), e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_dir(
+        &self,
+        local_dir: impl AsRef<std::path::Path>,
+        bucket: &str,
+        prefix: &str,
+        concurrency: usize,
+    ) -> crate::Result<Vec<(std::path::PathBuf, crate::Result<Object>)>> {
+        use futures_util::StreamExt;
+
+        let local_dir = local_dir.as_ref();
+        let files = files_in(local_dir).await?;
+        let uploads = files.into_iter().map(|path| async move {
+            let name = object_name(prefix, local_dir, &path);
+            let result = self
+                .create_from_file(bucket, &path, &name, mime_type(&path))
+                .await;
+            (path, result)
+        });
+        let mut results: Vec<_> = stream::iter(uploads)
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        results.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(results)
+    }
+
+    /// Obtain a list of objects within this Bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::{Object, ListRequest};
+    ///
+    /// let client = Client::default();
+    /// let all_objects = client.object().list("my_bucket", ListRequest::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+    ) -> crate::Result<impl Stream<Item = crate::Result<ObjectList>> + 'a> {
+        enum ListState {
+            Start(ListRequest),
+            HasMore(ListRequest),
+            Done,
+        }
+        use ListState::*;
+        impl ListState {
+            fn into_has_more(self) -> Option<ListState> {
+                match self {
+                    Start(req) | HasMore(req) => Some(HasMore(req)),
+                    Done => None,
+                }
+            }
+
+            fn req_mut(&mut self) -> Option<&mut ListRequest> {
+                match self {
+                    Start(ref mut req) | HasMore(ref mut req) => Some(req),
+                    Done => None,
+                }
+            }
+        }
+
+        if list_request.start_offset.is_some() && list_request.start_after.is_some() {
+            return Err(crate::Error::Other(
+                "start_offset and start_after can not both be set".to_string(),
+            ));
+        }
+        let client = self.0;
+
+        Ok(stream::unfold(
+            ListState::Start(list_request),
+            move |mut state| async move {
+                let url = format!("{}/b/{}/o", crate::BASE_URL, percent_encode(bucket));
+                let headers = match client.get_headers().await {
+                    Ok(h) => h,
+                    Err(e) => return Some((Err(e), state)),
+                };
+                let req = state.req_mut()?;
+                if req.max_results == Some(0) {
+                    return None;
+                }
+
+                let response = client
+                    .send(client.client.get(&url).query(req).headers(headers))
+                    .await;
+
+                let response = match response {
+                    Ok(r) if r.status() == 200 => r,
+                    Ok(r) => {
+                        let e = match r.json::<crate::error::GoogleErrorResponse>().await {
+                            Ok(err_res) => err_res.into(),
+                            Err(serde_err) => serde_err.into(),
+                        };
+                        return Some((Err(e), state));
+                    }
+                    Err(e) => return Some((Err(e.into()), state)),
+                };
+
+                let result: GoogleResponse<ObjectList> = match response.json().await {
+                    Ok(json) => json,
+                    Err(e) => return Some((Err(e.into()), state)),
+                };
+
+                let response_body = match result {
+                    GoogleResponse::Success(success) => success,
+                    GoogleResponse::Error(e) => return Some((Err(e.into()), state)),
+                };
+
+                let next_state = if let Some(ref page_token) = response_body.next_page_token {
+                    req.page_token = Some(page_token.clone());
+                    req.max_results = req
+                        .max_results
+                        .map(|rem| rem.saturating_sub(response_body.items.len()));
+                    state.into_has_more()?
+                } else {
+                    Done
+                };
+
+                Some((Ok(response_body), next_state))
+            },
+        ))
+    }
+
+    /// Obtain a list of objects within this Bucket, in the same way as `list`. While a page is
+    /// being consumed, up to `depth` of the following pages are already requested, so the network
+    /// latency of a listing overlaps with processing its results. A `depth` of zero is treated as
+    /// one.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{Client, ListRequest};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let client = Client::default();
+    /// let mut pages = client
+    ///     .object()
+    ///     .list_prefetched("my_bucket", ListRequest::default(), 2)
+    ///     .await?;
+    /// while let Some(page) = pages.try_next().await? {
+    ///     println!("{} objects", page.items.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_prefetched(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+        depth: usize,
+    ) -> crate::Result<impl Stream<Item = crate::Result<ObjectList>> + 'a> {
+        let pages = self.list(bucket, list_request).await?;
+        Ok(Prefetch::new(pages, depth))
+    }
+
+    /// Obtain the objects within this Bucket for which `predicate` returns `true`. Google can not
+    /// filter on most object properties, so the objects are filtered as the pages come in. Because
+    /// the result is streamed, only a single page of objects is kept in memory at any time.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{Client, ListRequest};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let client = Client::default();
+    /// let yesterday = chrono::Utc::now() - chrono::Duration::hours(24);
+    /// let recently_updated: Vec<_> = client
+    ///     .object()
+    ///     .list_filtered("my_bucket", ListRequest::default(), move |object| {
+    ///         object.updated > yesterday
+    ///     })
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_filtered<F>(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+        predicate: F,
+    ) -> crate::Result<impl Stream<Item = crate::Result<Object>> + 'a>
+    where
+        F: Fn(&Object) -> bool + 'a,
+    {
+        use futures_util::TryStreamExt;
+
+        let pages = self.list(bucket, list_request).await?;
+        Ok(pages
+            .map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+            .try_flatten()
+            .try_filter(move |object| std::future::ready(predicate(object))))
+    }
+
+    /// Obtain the objects and prefixes within this Bucket as a single stream of entries, in the
+    /// order of `ObjectList::into_entries` for every page. Together with a `delimiter` in
+    /// `list_request`, this lists a single level of a directory tree, where every
+    /// `ListEntry::Prefix` can be listed in turn to walk the tree.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{Client, ListRequest, object::ListEntry};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let client = Client::default();
+    /// let list_request = ListRequest {
+    ///     prefix: Some("photos/".to_string()),
+    ///     delimiter: Some("/".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let entries = client.object().list_entries("my_bucket", list_request).await?;
+    /// futures_util::pin_mut!(entries);
+    /// while let Some(entry) = entries.try_next().await? {
+    ///     match entry {
+    ///         ListEntry::Object(object) => println!("file {}", object.name),
+    ///         ListEntry::Prefix(prefix) => println!("directory {}", prefix),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_entries(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+    ) -> crate::Result<impl Stream<Item = crate::Result<ListEntry>> + 'a> {
+        use futures_util::TryStreamExt;
+
+        let pages = self.list(bucket, list_request).await?;
+        Ok(pages
+            .map_ok(|page| stream::iter(page.into_entries().into_iter().map(Ok)))
+            .try_flatten())
+    }
+
+    /// Counts the objects within this Bucket whose names start with `prefix`, and adds up their
+    /// sizes. Only the sizes of the objects are requested, and every page is added to the total
+    /// as it comes in, so this works for any number of objects without keeping them in memory.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let usage = client.object().usage("my_bucket", "logs/").await?;
+    /// println!("{} objects, {} bytes", usage.count, usage.total_bytes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn usage(&self, bucket: &str, prefix: &str) -> crate::Result<Usage> {
+        let url = format!("{}/b/{}/o", crate::BASE_URL, percent_encode(bucket));
+        let mut list_request = ListRequest {
+            prefix: Some(prefix.to_string()),
+            ..Default::default()
+        };
+        list_request.extra_query.insert(
+            "fields".to_string(),
+            "items(size),nextPageToken".to_string(),
+        );
+        let mut usage = Usage::default();
+        loop {
+            let response = self
+                .0
+                .send(
+                    self.0
+                        .client
+                        .get(&url)
+                        .query(&list_request)
+                        .headers(self.0.get_headers().await?),
+                )
+                .await?;
+            // every field of a page is optional, so an error response must not be parsed as one
+            if !response.status().is_success() {
+                return Err(crate::Error::from(
+                    response.json::<crate::error::GoogleErrorResponse>().await?,
+                ));
+            }
+            let page: SizePage = response.json().await?;
+            let next_page_token = add_usage(&mut usage, page);
+            match next_page_token {
+                Some(page_token) => list_request.page_token = Some(page_token),
+                None => return Ok(usage),
+            }
+        }
+    }
+
+    /// Obtains every generation of the object with the specified name in the specified bucket,
+    /// sorted from oldest to newest. Previous generations are only kept in buckets with object
+    /// versioning enabled, so in other buckets this returns at most the live object. Objects whose
+    /// names merely start with `file_name` are not included.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let history = client.object().versions("my_bucket", "config.json").await?;
+    /// for version in history {
+    ///     println!("{} updated at {}", version.generation, version.updated);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn versions(&self, bucket: &'a str, file_name: &str) -> crate::Result<Vec<Object>> {
+        use futures_util::TryStreamExt;
+
+        let list_request = ListRequest {
+            prefix: Some(file_name.to_string()),
+            versions: Some(true),
+            ..Default::default()
+        };
+        let name = file_name.to_string();
+        let mut versions: Vec<Object> = self
+            .list_filtered(bucket, list_request, move |object| object.name == name)
+            .await?
+            .try_collect()
+            .await?;
+        versions.sort_by_key(|object| object.generation);
+        Ok(versions)
+    }
+
+    /// Obtains a single object with the specified name in the specified bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::Object;
+    ///
+    /// let client = Client::default();
+    /// let object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read(&self, bucket: &str, file_name: &str) -> crate::Result<Object> {
+        self.read_with(bucket, file_name, ReadParameters::default())
+            .await
+    }
+
+    /// Obtains a single object with the specified name in the specified bucket, using the provided
+    /// `ReadParameters`. These can be used to control whether the acl of the object is included.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{Projection, ReadParameters};
+    ///
+    /// let client = Client::default();
+    /// let parameters = ReadParameters {
+    ///     projection: Some(Projection::Full),
+    ///     ..Default::default()
+    /// };
+    /// let object = client
+    ///     .object()
+    ///     .read_with("my_bucket", "path/to/my/file.png", parameters)
+    ///     .await?;
+    /// let acl = object.acl;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<Object> {
+        // only the plain metadata of an object is cached
+        let plain = ReadParameters {
+            generation: parameters.generation,
+            ..Default::default()
+        };
+        let cache = match self.0.metadata_cache {
+            Some(ref cache) if parameters == plain => Some(cache),
+            _ => None,
+        };
+        let generation = parameters.generation;
+        if let Some(object) = cache.and_then(|cache| cache.get(bucket, file_name, generation)) {
+            return Ok(object);
+        }
+        let url = format!(
+            "{}/b/{}/o/{}",
+            crate::BASE_URL,
+            percent_encode(bucket),
+            percent_encode(file_name),
+        );
+        let mut headers = self.0.get_headers().await?;
+        if let Some(ref encryption_key) = parameters.encryption_key {
+            headers.extend(encryption_key.headers()?);
+        }
+        let result: GoogleResponse<Object> = self
+            .0
+            .send(self.0.client.get(&url).headers(headers).query(&parameters))
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => {
+                if let Some(cache) = cache {
+                    cache.insert(&s, generation);
+                }
+                Ok(s)
+            }
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Waits until an object with the specified name exists in the specified bucket, and returns
+    /// it. The object is polled for every `poll_interval`, where the interval doubles after every
+    /// attempt that did not find the object, up to ten seconds or `poll_interval` if that is
+    /// longer. Intervals shorter than 50 milliseconds are raised to that. If the object has not
+    /// appeared after `timeout` has elapsed, `Error::Timeout(None)` is returned, without waiting
+    /// past the deadline. Any error other than the object not being found is returned immediately.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .wait_for("my_bucket", "path/to/my/file.png", Duration::from_millis(500), Duration::from_secs(60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> crate::Result<Object> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut interval = poll_interval.max(MIN_POLL_INTERVAL);
+        let max_interval = interval.max(MAX_POLL_INTERVAL);
+        loop {
+            match self.read(bucket, file_name).await {
+                Err(crate::Error::Google(e)) if e.error.code == StatusCode::NOT_FOUND => {}
+                result => return result,
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(crate::Error::Timeout(None));
+            }
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = interval.saturating_mul(2).min(max_interval);
+        }
     }
 
     /// Download the content of the object with the specified name in the specified bucket.
@@ -268,120 +1264,909 @@ impl<'a> ObjectClient<'a> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use cloud_storage::Client;
-    /// use cloud_storage::Object;
+    /// use cloud_storage::Object;
+    ///
+    /// let client = Client::default();
+    /// let bytes = client.object().download("my_bucket", "path/to/my/file.png").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download(&self, bucket: &str, file_name: &str) -> crate::Result<Vec<u8>> {
+        self.download_with(bucket, file_name, ReadParameters::default())
+            .await
+    }
+
+    /// Download the content of the object with the specified name in the specified bucket, using
+    /// the provided `ReadParameters`. These can be used to download a previous generation of the
+    /// object, or to download it only if it meets preconditions.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::ReadParameters;
+    ///
+    /// let client = Client::default();
+    /// let parameters = ReadParameters {
+    ///     generation: Some(1617000000000000),
+    ///     ..Default::default()
+    /// };
+    /// let bytes = client
+    ///     .object()
+    ///     .download_with("my_bucket", "path/to/my/file.png", parameters)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<Vec<u8>> {
+        let response = self
+            .download_response(bucket, file_name, parameters)
+            .await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Starts downloading the content of the object with the specified name in the specified
+    /// bucket, and returns the response once Google has accepted the request. The content can then
+    /// be read from the response in any way, and its headers can be inspected. This is useful for
+    /// passing the content on to a web framework, for example.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{ObjectHashes, ReadParameters};
+    ///
+    /// let client = Client::default();
+    /// let response = client
+    ///     .object()
+    ///     .download_response("my_bucket", "path/to/my/file.png", ReadParameters::default())
+    ///     .await?;
+    /// let hashes = ObjectHashes::from_headers(response.headers());
+    /// let body = reqwest::Body::wrap_stream(response.bytes_stream());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_response(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<reqwest::Response> {
+        self.send_download(bucket, file_name, &parameters, None, None)
+            .await
+    }
+
+    /// Returns a `DownloadBuilder` to download the content of the object with the specified name
+    /// in the specified bucket, which can choose between the stored and the decompressed content
+    /// of gzip compressed objects.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let text = client
+    ///     .object()
+    ///     .fetch("my_bucket", "logs/2024-01-01.txt")
+    ///     .raw(false)
+    ///     .bytes()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch(&self, bucket: &str, file_name: &str) -> DownloadBuilder<'a> {
+        DownloadBuilder::new(ObjectClient(self.0), bucket, file_name)
+    }
+
+    // Builds the request for the content of an object, with the `Accept-Encoding` header set to
+    // `accept_encoding` and the headers of `encryption_key` added if they are given. Both the
+    // buffered and the streamed downloads are sent this way.
+    async fn download_request(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: &ReadParameters,
+        accept_encoding: Option<&str>,
+        encryption_key: Option<&CustomerEncryptionKey>,
+    ) -> crate::Result<reqwest::RequestBuilder> {
+        let url = format!(
+            "{}/b/{}/o/{}?alt=media",
+            crate::BASE_URL,
+            percent_encode(bucket),
+            percent_encode(file_name),
+        );
+        let mut headers = self.0.get_headers().await?;
+        if let Some(accept_encoding) = accept_encoding {
+            headers.insert(reqwest::header::ACCEPT_ENCODING, accept_encoding.parse()?);
+        }
+        if let Some(encryption_key) = encryption_key.or(parameters.encryption_key.as_ref()) {
+            headers.extend(encryption_key.headers()?);
+        }
+        Ok(self.0.client.get(&url).headers(headers).query(parameters))
+    }
+
+    // Sends the download of the content of an object, see `download_request` and
+    // `download_response`.
+    pub(super) async fn send_download(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: &ReadParameters,
+        accept_encoding: Option<&str>,
+        encryption_key: Option<&CustomerEncryptionKey>,
+    ) -> crate::Result<reqwest::Response> {
+        let request = self
+            .download_request(
+                bucket,
+                file_name,
+                parameters,
+                accept_encoding,
+                encryption_key,
+            )
+            .await?;
+        checked_download(self.0.send(request).await?).await
+    }
+
+    /// Download the content of `object`, using its `media_link`. Unlike `download`, which fetches
+    /// the live version of the object with the same name, this fetches exactly the generation that
+    /// `object` describes, which matters in buckets with object versioning enabled.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// let bytes = client.object().download_object(&object).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_object(&self, object: &Object) -> crate::Result<Vec<u8>> {
+        let resp = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .get(&object.media_link)
+                    .headers(self.0.get_headers().await?),
+            )
+            .await?;
+        Ok(checked_download(resp).await?.bytes().await?.to_vec())
+    }
+
+    /// Obtains both the metadata and the content of the object with the specified name in the
+    /// specified bucket. The content is downloaded from the exact generation that the metadata
+    /// describes, so the two always match, even if the object is replaced in between.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let (object, bytes) = client
+    ///     .object()
+    ///     .read_and_download("my_bucket", "path/to/my/config.json")
+    ///     .await?;
+    /// println!("generation {} is {} bytes", object.generation, bytes.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_and_download(
+        &self,
+        bucket: &str,
+        file_name: &str,
+    ) -> crate::Result<(Object, Vec<u8>)> {
+        // cached metadata may describe a generation that was overwritten by someone else since, so
+        // the metadata is always read fresh here
+        self.forget(bucket, file_name);
+        let object = self.read(bucket, file_name).await?;
+        let content = self.download_object(&object).await?;
+        Ok((object, content))
+    }
+
+    /// Download the content of the object with the specified name in the specified bucket, without
+    /// allocating the whole file into a vector. The returned stream is `Send`, so it can be moved
+    /// into a task on a multi-threaded runtime.
+    ///
+    /// Errors that Google returns before the download starts are returned directly. If the
+    /// connection fails while the content is being streamed, the stream yields an error and
+    /// ends, so callers must check every item. Use `download_streamed_resumable` to recover from
+    /// such failures automatically.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::Object;
+    /// use futures_util::stream::StreamExt;
+    /// use tokio::fs::File;
+    /// use tokio::io::{AsyncWriteExt, BufWriter};
+    ///
+    /// let client = Client::default();
+    /// let mut stream = client.object().download_streamed("my_bucket", "path/to/my/file.png").await?;
+    /// let mut file = BufWriter::new(File::create("file.png").await.unwrap());
+    /// while let Some(byte) = stream.next().await {
+    ///     file.write_all(&[byte.unwrap()]).await.unwrap();
+    /// }
+    /// file.flush().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_streamed(
+        &self,
+        bucket: &str,
+        file_name: &str,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        self.download_streamed_with(bucket, file_name, ReadParameters::default())
+            .await
+    }
+
+    /// Download the content of the object with the specified name in the specified bucket in the
+    /// same way as `download_streamed`, using the provided `ReadParameters`. These can be used to
+    /// stream a previous generation of the object, or an object that is encrypted with a
+    /// customer-supplied encryption key.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{CustomerEncryptionKey, ReadParameters};
+    ///
+    /// let client = Client::default();
+    /// let parameters = ReadParameters {
+    ///     encryption_key: Some(CustomerEncryptionKey::new([7; 32])),
+    ///     ..Default::default()
+    /// };
+    /// let stream = client
+    ///     .object()
+    ///     .download_streamed_with("my_bucket", "path/to/my/secret.bin", parameters)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_streamed_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        self.send_download_streamed(bucket, file_name, &parameters, None, None)
+            .await
+    }
+
+    // Sends the download of the content of an object and streams its bytes, see
+    // `download_request` and `download_streamed`.
+    pub(super) async fn send_download_streamed(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: &ReadParameters,
+        accept_encoding: Option<&str>,
+        encryption_key: Option<&CustomerEncryptionKey>,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        use futures_util::{StreamExt, TryStreamExt};
+        let request = self
+            .download_request(
+                bucket,
+                file_name,
+                parameters,
+                accept_encoding,
+                encryption_key,
+            )
+            .await?;
+        let response = checked_download(self.0.send(request).await?).await?;
+        let size = response.content_length();
+        let hashes = ObjectHashes::from_headers(response.headers());
+        let bytes = response
+            .bytes_stream()
+            .map(|chunk| chunk.map(|c| futures_util::stream::iter(c.into_iter().map(Ok))))
+            .try_flatten();
+        Ok(SizedByteStream::new(bytes, size, hashes))
+    }
+
+    /// Download the content of the object with the specified name in the specified bucket, in the
+    /// same way as `download_streamed`. If the connection fails while the content is being
+    /// streamed, the download is resumed from the last received byte using a `Range` request, at
+    /// most `max_retries` times. Resumed requests are pinned to the generation of the object that
+    /// the download started with, so the content can not change halfway through.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use futures_util::stream::StreamExt;
+    /// use tokio::fs::File;
+    /// use tokio::io::{AsyncWriteExt, BufWriter};
+    ///
+    /// let client = Client::default();
+    /// let mut stream = client
+    ///     .object()
+    ///     .download_streamed_resumable("my_bucket", "path/to/my/large_file.bin", 3)
+    ///     .await?;
+    /// let mut file = BufWriter::new(File::create("large_file.bin").await?);
+    /// while let Some(byte) = stream.next().await {
+    ///     file.write_all(&[byte?]).await?;
+    /// }
+    /// file.flush().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_streamed_resumable(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        max_retries: u32,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        self.download_streamed_resumable_with(
+            bucket,
+            file_name,
+            ReadParameters::default(),
+            max_retries,
+        )
+        .await
+    }
+
+    /// Download the content of the object with the specified name in the specified bucket in the
+    /// same way as `download_streamed_resumable`, using the provided `ReadParameters`. These are
+    /// sent with every resumed request as well.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{CustomerEncryptionKey, ReadParameters};
+    ///
+    /// let client = Client::default();
+    /// let parameters = ReadParameters {
+    ///     encryption_key: Some(CustomerEncryptionKey::new([7; 32])),
+    ///     ..Default::default()
+    /// };
+    /// let stream = client
+    ///     .object()
+    ///     .download_streamed_resumable_with("my_bucket", "path/to/my/secret.bin", parameters, 3)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_streamed_resumable_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+        max_retries: u32,
+    ) -> crate::Result<SizedByteStream<impl Stream<Item = crate::Result<u8>> + Send + Unpin>> {
+        use futures_util::{StreamExt, TryStreamExt};
+        let request = self
+            .download_request(bucket, file_name, &parameters, None, None)
+            .await?
+            .build()?;
+        let mut url = request.url().clone();
+        let headers = request.headers().clone();
+        let response = self
+            .0
+            .send(self.0.client.get(url.clone()).headers(headers.clone()))
+            .await?;
+        let response = checked_download(response).await?;
+        let size = response.content_length();
+        let hashes = ObjectHashes::from_headers(response.headers());
+        let generation = response
+            .headers()
+            .get("x-goog-generation")
+            .and_then(|generation| generation.to_str().ok());
+        if let (None, Some(generation)) = (parameters.generation, generation) {
+            url.query_pairs_mut().append_pair("generation", generation);
+        }
+        let client = self.0.client.clone();
+        let metrics_sink = self.0.metrics_sink.clone();
+        let bytes = resumable_bytes(
+            client,
+            metrics_sink,
+            url.to_string(),
+            headers,
+            response,
+            max_retries,
+        )
+        .map(|chunk| chunk.map(|c| futures_util::stream::iter(c.into_iter().map(Ok))))
+        .try_flatten();
+        Ok(SizedByteStream::new(bytes, size, hashes))
+    }
+
+    /// Opens the object with the specified name in the specified bucket for random access. Only
+    /// the metadata of the object is read here; the returned `RangeReader` implements `AsyncRead`
+    /// and `AsyncSeek`, and downloads the parts of the content that are actually read, using range
+    /// requests. All reads are made against the generation of the object that was opened, and
+    /// seeks are bounded by its size.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use std::io::SeekFrom;
+    /// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+    ///
+    /// let client = Client::default();
+    /// let mut reader = client.object().open_range_reader("my_bucket", "path/to/my/archive.zip").await?;
+    /// let mut trailer = [0; 22];
+    /// reader.seek(SeekFrom::End(-22)).await?;
+    /// reader.read_exact(&mut trailer).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_range_reader(
+        &self,
+        bucket: &str,
+        file_name: &str,
+    ) -> crate::Result<RangeReader<'a>> {
+        let object = self.read(bucket, file_name).await?;
+        Ok(RangeReader::new(self.0, object.media_link, object.size))
+    }
+
+    /// Updates a single object with the specified name in the specified bucket with the new
+    /// information in `object`.
+    ///
+    /// Only the metadata of an object can be updated, so it can not be renamed or moved this way.
+    /// When the `name` or `bucket` fields were changed, an error is returned without sending a
+    /// request. Use [`rewrite`](Self::rewrite) or [`copy`](Self::copy) followed by
+    /// [`delete`](Self::delete) to rename an object instead.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::Object;
+    ///
+    /// let client = Client::default();
+    /// let mut object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// object.content_type = Some("application/xml".to_string());
+    /// client.object().update(&object).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(&self, object: &Object) -> crate::Result<Object> {
+        self.update_with(object, &Preconditions::default()).await
+    }
+
+    /// Updates a single object in the same way as `update`, but only if the object still meets
+    /// `preconditions`. This can be used to make sure that no one else changed the object since it
+    /// was read. If it did change, `Error::PreconditionFailed` is returned.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::Preconditions;
+    ///
+    /// let client = Client::default();
+    /// let mut object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// let preconditions = Preconditions {
+    ///     if_metageneration_match: Some(object.metageneration),
+    ///     ..Default::default()
+    /// };
+    /// object.content_type = Some("application/xml".to_string());
+    /// client.object().update_with(&object, &preconditions).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_with(
+        &self,
+        object: &Object,
+        preconditions: &Preconditions,
+    ) -> crate::Result<Object> {
+        if object.is_renamed() {
+            return Err(crate::Error::Other(format!(
+                "the name or bucket of object {} was changed, which an update can not do; use \
+                rewrite or copy, followed by delete, to rename it",
+                object.id
+            )));
+        }
+        let url = format!(
+            "{}/b/{}/o/{}",
+            crate::BASE_URL,
+            percent_encode(&object.bucket),
+            percent_encode(&object.name),
+        );
+        let result: GoogleResponse<Object> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .put(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(preconditions)
+                    .json(&object),
+            )
+            .await?
+            .json()
+            .await?;
+        self.forget(&object.bucket, &object.name);
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Places or releases a temporary hold on the object with the specified name in the specified
+    /// bucket. Only the `temporaryHold` field is patched, so other metadata of the object is left
+    /// untouched.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .set_temporary_hold("my_bucket", "path/to/my/file.png", true)
+    ///     .await?;
+    /// assert_eq!(object.temporary_hold, Some(true));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_temporary_hold(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        hold: bool,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "temporaryHold": hold });
+        self.patch(bucket, file_name, &body, &[]).await
+    }
+
+    /// Places or releases an event-based hold on the object with the specified name in the
+    /// specified bucket. Only the `eventBasedHold` field is patched, so other metadata of the
+    /// object is left untouched.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .set_event_based_hold("my_bucket", "path/to/my/file.png", false)
+    ///     .await?;
+    /// assert_eq!(object.event_based_hold, Some(false));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_event_based_hold(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        hold: bool,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "eventBasedHold": hold });
+        self.patch(bucket, file_name, &body, &[]).await
+    }
+
+    /// Sets the custom time of the object with the specified name in the specified bucket. Lifecycle
+    /// rules can use the `days_since_custom_time` condition to act on this timestamp. Once set, the
+    /// custom time can only be moved forward. Other metadata of the object is left untouched.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let invoice_date = chrono::Utc::now();
+    /// let object = client
+    ///     .object()
+    ///     .set_custom_time("my_bucket", "invoices/2021-001.pdf", invoice_date)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_custom_time(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        custom_time: chrono::DateTime<chrono::Utc>,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "customTime": custom_time });
+        self.patch(bucket, file_name, &body, &[]).await
+    }
+
+    /// Sets the `Content-Disposition` header that Google serves along with the content of the
+    /// object with the specified name in the specified bucket. Only the `contentDisposition` field
+    /// is patched, so other metadata of the object is left untouched.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
     ///
     /// let client = Client::default();
-    /// let bytes = client.object().download("my_bucket", "path/to/my/file.png").await?;
+    /// let object = client
+    ///     .object()
+    ///     .set_content_disposition(
+    ///         "my_bucket",
+    ///         "exports/8f2e1c.csv",
+    ///         "attachment; filename=\"report.csv\"",
+    ///     )
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn download(&self, bucket: &str, file_name: &str) -> crate::Result<Vec<u8>> {
-        let url = format!(
-            "{}/b/{}/o/{}?alt=media",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(file_name),
-        );
-        let resp = self
-            .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
-            .await?;
-        if resp.status() == StatusCode::NOT_FOUND {
-            Err(crate::Error::Other(resp.text().await?))
-        } else {
-            Ok(resp.error_for_status()?.bytes().await?.to_vec())
-        }
+    pub async fn set_content_disposition(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        content_disposition: &str,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "contentDisposition": content_disposition });
+        self.patch(bucket, file_name, &body, &[]).await
     }
 
-    /// Download the content of the object with the specified name in the specified bucket, without
-    /// allocating the whole file into a vector.
+    /// Sets the `Content-Language` header that Google serves along with the content of the object
+    /// with the specified name in the specified bucket. Only the `contentLanguage` field is
+    /// patched, so other metadata of the object is left untouched.
     /// ### Example
     /// ```no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use cloud_storage::Client;
-    /// use cloud_storage::Object;
-    /// use futures_util::stream::StreamExt;
-    /// use tokio::fs::File;
-    /// use tokio::io::{AsyncWriteExt, BufWriter};
     ///
     /// let client = Client::default();
-    /// let mut stream = client.object().download_streamed("my_bucket", "path/to/my/file.png").await?;
-    /// let mut file = BufWriter::new(File::create("file.png").await.unwrap());
-    /// while let Some(byte) = stream.next().await {
-    ///     file.write_all(&[byte.unwrap()]).await.unwrap();
-    /// }
-    /// file.flush().await?;
+    /// let object = client
+    ///     .object()
+    ///     .set_content_language("my_bucket", "site/nl/index.html", "nl")
+    ///     .await?;
+    /// assert_eq!(object.content_language.as_deref(), Some("nl"));
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn download_streamed(
+    pub async fn set_content_language(
         &self,
         bucket: &str,
         file_name: &str,
-    ) -> crate::Result<impl Stream<Item = crate::Result<u8>> + Unpin> {
-        use futures_util::{StreamExt, TryStreamExt};
-        let url = format!(
-            "{}/b/{}/o/{}?alt=media",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(file_name),
-        );
-        let response = self
-            .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
-            .await?
-            .error_for_status()?;
-        let size = response.content_length();
-        let bytes = response
-            .bytes_stream()
-            .map(|chunk| chunk.map(|c| futures_util::stream::iter(c.into_iter().map(Ok))))
-            .try_flatten();
-        Ok(SizedByteStream::new(bytes, size))
+        content_language: &str,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "contentLanguage": content_language });
+        self.patch(bucket, file_name, &body, &[]).await
     }
 
-    /// Updates a single object with the specified name in the specified bucket with the new
-    /// information in `object`.
+    /// Sets the `Cache-Control` header that Google, and Cloud CDN, serve along with the content of
+    /// the object with the specified name in the specified bucket. Only the `cacheControl` field
+    /// is patched, so other metadata of the object is left untouched.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .set_cache_control("my_bucket", "assets/app.3f9a1c.js", "public, max-age=31536000")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_cache_control(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        cache_control: &str,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "cacheControl": cache_control });
+        self.patch(bucket, file_name, &body, &[]).await
+    }
+
+    /// Sets the `Cache-Control` header of all objects in the specified bucket whose names start
+    /// with `prefix`, in the same way as `set_cache_control`, and returns how many objects were
+    /// updated. The objects are updated while they are being listed, with at most `concurrency`
+    /// updates at the same time.
     ///
-    /// Note that if the `name` or `bucket` fields are changed, the object will not be found.
-    /// See [`rewrite`] or [`copy`] for similar operations.
+    /// An object that can not be updated does not stop the others from being updated. Instead, an
+    /// error that describes every failed update is returned once all objects have been handled.
     /// ### Example
     /// ```no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use cloud_storage::Client;
-    /// use cloud_storage::Object;
     ///
     /// let client = Client::default();
-    /// let mut object = client.object().read("my_bucket", "path/to/my/file.png").await?;
-    /// object.content_type = Some("application/xml".to_string());
-    /// client.object().update(&object).await?;
+    /// let updated = client
+    ///     .object()
+    ///     .set_cache_control_prefix("my_bucket", "assets/", "public, max-age=31536000", 16)
+    ///     .await?;
+    /// println!("updated {} objects", updated);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update(&self, object: &Object) -> crate::Result<Object> {
+    pub async fn set_cache_control_prefix(
+        &self,
+        bucket: &'a str,
+        prefix: &str,
+        cache_control: &str,
+        concurrency: usize,
+    ) -> crate::Result<usize> {
+        let update = |name: String| async move {
+            self.set_cache_control(bucket, &name, cache_control)
+                .await
+                .map(drop)
+        };
+        self.for_each_with_prefix(bucket, prefix, concurrency, "updated", update)
+            .await
+    }
+
+    /// Sets the retention configuration of the object with the specified name in the specified
+    /// bucket. Shortening an unlocked retention configuration is only allowed when
+    /// `override_unlocked_retention` is set. Other metadata of the object is left untouched.
+    ///
+    /// A locked retention configuration can only be extended. Attempts to shorten it, or to unlock
+    /// it, fail with `Error::RetentionLocked`.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{ObjectRetention, RetentionMode};
+    ///
+    /// let client = Client::default();
+    /// let retention = ObjectRetention {
+    ///     mode: RetentionMode::Unlocked,
+    ///     retain_until_time: chrono::Utc::now() + chrono::Duration::days(30),
+    /// };
+    /// let object = client
+    ///     .object()
+    ///     .set_retention("my_bucket", "path/to/my/file.png", retention, false)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_retention(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        retention: ObjectRetention,
+        override_unlocked_retention: bool,
+    ) -> crate::Result<Object> {
+        self.patch_retention(
+            bucket,
+            file_name,
+            Some(retention),
+            override_unlocked_retention,
+        )
+        .await
+    }
+
+    /// Removes the unlocked retention configuration of the object with the specified name in the
+    /// specified bucket, so that it can be deleted or replaced again. A locked retention
+    /// configuration can not be removed, and fails with `Error::RetentionLocked`.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client
+    ///     .object()
+    ///     .clear_retention("my_bucket", "path/to/my/file.png")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clear_retention(&self, bucket: &str, file_name: &str) -> crate::Result<Object> {
+        self.patch_retention(bucket, file_name, None, true).await
+    }
+
+    async fn patch_retention(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        retention: Option<ObjectRetention>,
+        override_unlocked_retention: bool,
+    ) -> crate::Result<Object> {
+        let body = serde_json::json!({ "retention": retention });
+        let query = [(
+            "overrideUnlockedRetention",
+            if override_unlocked_retention {
+                "true"
+            } else {
+                "false"
+            },
+        )];
+        match self.patch(bucket, file_name, &body, &query).await {
+            Err(crate::Error::Google(e)) => {
+                // Google does not say why a change was rejected, so find out whether it is because
+                // the current configuration is locked.
+                match self.read(bucket, file_name).await {
+                    Ok(Object {
+                        retention: Some(current),
+                        ..
+                    }) if !current.permits(retention.as_ref()) => {
+                        Err(crate::Error::RetentionLocked(current.retain_until_time))
+                    }
+                    _ => Err(crate::Error::Google(e)),
+                }
+            }
+            result => result,
+        }
+    }
+
+    // Makes sure that a streamed upload that Google accepted did not stop early. If it did, the
+    // partial object is removed again.
+    async fn check_upload(
+        &self,
+        object: Object,
+        progress: &UploadProgress,
+        length: Option<u64>,
+    ) -> crate::Result<Object> {
+        use std::sync::atomic::Ordering;
+
+        let sent = progress.sent.load(Ordering::SeqCst);
+        let failed = progress.failed.load(Ordering::SeqCst);
+        if !failed && length.is_none_or(|length| length == sent) {
+            self.forget(&object.bucket, &object.name);
+            return Ok(object);
+        }
+        let preconditions = Preconditions {
+            if_generation_match: Some(object.generation),
+            ..Default::default()
+        };
+        let deleted = self
+            .delete_with(&object.bucket, &object.name, &preconditions)
+            .await;
+        Err(crate::Error::Other(match deleted {
+            Ok(()) => format!(
+                "the upload of {} stopped after {} bytes, the partial object was deleted",
+                object.name, sent
+            ),
+            Err(e) => format!(
+                "the upload of {} stopped after {} bytes, and the partial object with generation {} \
+                could not be deleted: {}",
+                object.name, sent, object.generation, e
+            ),
+        }))
+    }
+
+    // Removes the object from the metadata cache, after it was changed through this client.
+    pub(super) fn forget(&self, bucket: &str, file_name: &str) {
+        if let Some(ref cache) = self.0.metadata_cache {
+            cache.invalidate(bucket, file_name);
+        }
+    }
+
+    async fn patch(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        body: &serde_json::Value,
+        query: &[(&str, &str)],
+    ) -> crate::Result<Object> {
         let url = format!(
             "{}/b/{}/o/{}",
             crate::BASE_URL,
-            percent_encode(&object.bucket),
-            percent_encode(&object.name),
+            percent_encode(bucket),
+            percent_encode(file_name),
         );
         let result: GoogleResponse<Object> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(&object)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .patch(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(query)
+                    .json(body),
+            )
             .await?
             .json()
             .await?;
+        self.forget(bucket, file_name);
         match result {
             GoogleResponse::Success(s) => Ok(s),
             GoogleResponse::Error(e) => Err(e.into()),
@@ -402,6 +2187,39 @@ impl<'a> ObjectClient<'a> {
     /// # }
     /// ```
     pub async fn delete(&self, bucket: &str, file_name: &str) -> crate::Result<()> {
+        self.delete_with(bucket, file_name, &Preconditions::default())
+            .await
+    }
+
+    /// Deletes a single object with the specified name in the specified bucket, but only if it
+    /// meets `preconditions`.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::Preconditions;
+    ///
+    /// let client = Client::default();
+    /// let object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// // only delete the version of the file that was just read
+    /// let preconditions = Preconditions {
+    ///     if_generation_match: Some(object.generation),
+    ///     ..Default::default()
+    /// };
+    /// client
+    ///     .object()
+    ///     .delete_with("my_bucket", "path/to/my/file.png", &preconditions)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        preconditions: &Preconditions,
+    ) -> crate::Result<()> {
         let url = format!(
             "{}/b/{}/o/{}",
             crate::BASE_URL,
@@ -410,15 +2228,174 @@ impl<'a> ObjectClient<'a> {
         );
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .delete(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(preconditions),
+            )
             .await?;
+        self.forget(bucket, file_name);
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
+        }
+    }
+
+    /// Deletes all objects in the specified bucket whose names start with `prefix`, and returns
+    /// how many were deleted. The objects are deleted while they are being listed, with at most
+    /// `concurrency` deletions at the same time, so the names are never all kept in memory.
+    ///
+    /// An object that can not be deleted does not stop the others from being deleted. Instead, an
+    /// error that describes every failed deletion is returned once all objects have been handled.
+    /// An error while listing the objects does stop the deletion, because the remaining objects
+    /// can not be found.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let deleted = client.object().delete_prefix("my_bucket", "tmp/2023/", 16).await?;
+    /// println!("deleted {} objects", deleted);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_prefix(
+        &self,
+        bucket: &'a str,
+        prefix: &str,
+        concurrency: usize,
+    ) -> crate::Result<usize> {
+        let delete = |name: String| async move { self.delete(bucket, &name).await };
+        self.for_each_with_prefix(bucket, prefix, concurrency, "deleted", delete)
+            .await
+    }
+
+    // Applies `action` to the name of every object in `bucket` whose name starts with `prefix`, at
+    // most `concurrency` at the same time, and returns the number of objects for which it
+    // succeeded. `done` describes the action in error messages, for example `deleted`.
+    async fn for_each_with_prefix<F, Fut>(
+        &self,
+        bucket: &'a str,
+        prefix: &str,
+        concurrency: usize,
+        done: &str,
+        action: F,
+    ) -> crate::Result<usize>
+    where
+        F: Fn(String) -> Fut,
+        Fut: std::future::Future<Output = crate::Result<()>>,
+    {
+        use futures_util::TryStreamExt;
+
+        let list_request = ListRequest {
+            prefix: Some(prefix.to_string()),
+            ..Default::default()
+        };
+        let names = self
+            .list(bucket, list_request)
+            .await?
+            .map_ok(|page| {
+                stream::iter(
+                    page.items
+                        .into_iter()
+                        .map(|object| crate::Result::Ok(object.name)),
+                )
+            })
+            .try_flatten();
+        let results = names
+            .map_ok(|name| {
+                let result = action(name.clone());
+                async move { Ok((name, result.await)) }
+            })
+            .try_buffer_unordered(concurrency.max(1));
+        futures_util::pin_mut!(results);
+
+        let mut succeeded = 0;
+        let mut failures = Vec::new();
+        while let Some((name, result)) = results.try_next().await? {
+            match result {
+                Ok(()) => succeeded += 1,
+                Err(e) => failures.push(format!("{}: {}", name, e)),
+            }
+        }
+        if failures.is_empty() {
+            Ok(succeeded)
+        } else {
+            Err(crate::Error::Other(format!(
+                "{} {} objects with prefix {}, but {} could not be {}: {}",
+                done,
+                succeeded,
+                prefix,
+                failures.len(),
+                done,
+                failures.join("; ")
+            )))
+        }
+    }
+
+    /// Restores a soft-deleted object with the specified name in the specified bucket, and returns
+    /// the restored object. `generation` selects the version of the object to restore. Google
+    /// requires a `restore_token` as well when multiple soft-deleted objects with the same name
+    /// and generation exist, which is the case in buckets with hierarchical namespace enabled.
+    ///
+    /// A soft-deleted object can only be restored during the soft delete retention period of its
+    /// bucket.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let object = client.object().read("my_bucket", "path/to/my/file.png").await?;
+    /// client.object().delete("my_bucket", "path/to/my/file.png").await?;
+    /// let restored = client
+    ///     .object()
+    ///     .restore_soft_deleted("my_bucket", "path/to/my/file.png", object.generation, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_soft_deleted(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        generation: i64,
+        restore_token: Option<String>,
+    ) -> crate::Result<Object> {
+        let url = format!(
+            "{}/b/{}/o/{}/restore",
+            crate::BASE_URL,
+            percent_encode(bucket),
+            percent_encode(file_name),
+        );
+        let mut query = vec![("generation", generation.to_string())];
+        if let Some(restore_token) = restore_token {
+            query.push(("restoreToken", restore_token));
+        }
+        let result: GoogleResponse<Object> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&query),
+            )
+            .await?
+            .json()
+            .await?;
+        self.forget(bucket, file_name);
+        match result {
+            GoogleResponse::Success(s) => Ok(s),
+            GoogleResponse::Error(e) => Err(e.into()),
         }
     }
 
@@ -454,12 +2431,84 @@ impl<'a> ObjectClient<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn compose(
+    pub async fn compose(
+        &self,
+        bucket: &str,
+        req: &ComposeRequest,
+        destination_object: &str,
+    ) -> crate::Result<Object> {
+        self.compose_with(
+            bucket,
+            req,
+            destination_object,
+            ComposeParameters::default(),
+        )
+        .await
+    }
+
+    /// Concatenates the contents of multiple objects into one, using the provided
+    /// `ComposeParameters`. These can be used to set preconditions on the destination object.
+    ///
+    /// Fails without contacting Google if `req` contains no source objects, or more than the 32
+    /// source objects that Google allows.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{ComposeParameters, ComposeRequest, Preconditions, SourceObject};
+    ///
+    /// let client = Client::default();
+    /// let compose_request = ComposeRequest {
+    ///     kind: "storage#composeRequest".to_string(),
+    ///     source_objects: vec![
+    ///         SourceObject {
+    ///             name: "file1".to_string(),
+    ///             generation: None,
+    ///             object_preconditions: None,
+    ///         },
+    ///         SourceObject {
+    ///             name: "file2".to_string(),
+    ///             generation: None,
+    ///             object_preconditions: None,
+    ///         },
+    ///     ],
+    ///     destination: None,
+    /// };
+    /// // only succeeds if `test-concatted-file` does not exist yet
+    /// let parameters = ComposeParameters {
+    ///     preconditions: Preconditions {
+    ///         if_generation_match: Some(0),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// let obj3 = client
+    ///     .object()
+    ///     .compose_with("my_bucket", &compose_request, "test-concatted-file", parameters)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compose_with(
         &self,
         bucket: &str,
         req: &ComposeRequest,
         destination_object: &str,
+        parameters: ComposeParameters,
     ) -> crate::Result<Object> {
+        if req.source_objects.is_empty() {
+            return Err(crate::Error::new(
+                "a compose request requires at least one source object",
+            ));
+        }
+        if req.source_objects.len() > MAX_COMPOSE_SOURCES {
+            return Err(crate::Error::Other(format!(
+                "a compose request may contain at most {} source objects, but {} were supplied",
+                MAX_COMPOSE_SOURCES,
+                req.source_objects.len(),
+            )));
+        }
         let url = format!(
             "{}/b/{}/o/{}/compose",
             crate::BASE_URL,
@@ -468,20 +2517,151 @@ impl<'a> ObjectClient<'a> {
         );
         let result: GoogleResponse<Object> = self
             .0
-            .client
-            .post(&url)
-            .headers(self.0.get_headers().await?)
-            .json(req)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&parameters)
+                    .json(req),
+            )
             .await?
             .json()
             .await?;
+        self.forget(bucket, destination_object);
         match result {
             GoogleResponse::Success(s) => Ok(s),
             GoogleResponse::Error(e) => Err(e.into()),
         }
     }
 
+    /// Concatenates the contents of any number of objects into one. When there are more than the
+    /// 32 source objects that a single compose request accepts, they are first composed in groups
+    /// into intermediate objects, which are named after `destination_object` and composed in
+    /// turn. The `preconditions` in `parameters` only apply to the destination object.
+    ///
+    /// The intermediate objects are deleted once the destination object has been composed, and
+    /// also when composing fails. The composed object is returned along with the names of the
+    /// intermediate objects that could not be deleted, so they can be removed later. After a
+    /// failure, the error lists these names instead. When the returned future is dropped before
+    /// it completes, the intermediate objects are deleted in the background, and the ones that
+    /// can not be deleted are logged as a warning through the `log` crate.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{ComposeParameters, SourceObject};
+    ///
+    /// let client = Client::default();
+    /// let parts = (0..100)
+    ///     .map(|i| SourceObject {
+    ///         name: format!("upload/part-{:03}", i),
+    ///         generation: None,
+    ///         object_preconditions: None,
+    ///     })
+    ///     .collect();
+    /// let (object, leftover) = client
+    ///     .object()
+    ///     .compose_all("my_bucket", parts, "upload/complete", ComposeParameters::default())
+    ///     .await?;
+    /// for name in leftover {
+    ///     eprintln!("intermediate object {} was left behind", name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compose_all(
+        &self,
+        bucket: &str,
+        source_objects: Vec<SourceObject>,
+        destination_object: &str,
+        parameters: ComposeParameters,
+    ) -> crate::Result<(Object, Vec<String>)> {
+        let mut temp_objects = TempObjects {
+            client: self.0,
+            bucket,
+            names: Vec::new(),
+        };
+        let result = self
+            .compose_chained(
+                bucket,
+                source_objects,
+                destination_object,
+                parameters,
+                &mut temp_objects,
+            )
+            .await;
+        let leftover = temp_objects.delete_all().await;
+        match result {
+            Ok(object) => Ok((object, leftover)),
+            Err(e) if !leftover.is_empty() => Err(crate::Error::Other(format!(
+                "{}; the intermediate objects {} could not be deleted",
+                e,
+                leftover.join(", ")
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Composes `source_objects` into `destination_object`, via intermediate objects that are
+    // recorded in `temp_objects` when there are too many sources for a single request.
+    async fn compose_chained(
+        &self,
+        bucket: &str,
+        mut source_objects: Vec<SourceObject>,
+        destination_object: &str,
+        parameters: ComposeParameters,
+        temp_objects: &mut TempObjects<'_>,
+    ) -> crate::Result<Object> {
+        let intermediate_parameters = ComposeParameters {
+            kms_key_name: parameters.kms_key_name.clone(),
+            ..Default::default()
+        };
+        // keeps the intermediate objects of concurrent compositions apart
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos());
+        let mut level = 0;
+        while source_objects.len() > MAX_COMPOSE_SOURCES {
+            let mut sources = source_objects.into_iter().peekable();
+            let mut intermediates = Vec::new();
+            while sources.peek().is_some() {
+                let name = format!(
+                    "{}.compose-{:x}-{}-{}",
+                    destination_object,
+                    nonce,
+                    level,
+                    intermediates.len()
+                );
+                let req = ComposeRequest {
+                    kind: "storage#composeRequest".to_string(),
+                    source_objects: sources.by_ref().take(MAX_COMPOSE_SOURCES).collect(),
+                    destination: None,
+                };
+                // recorded before the request, which may create the object even if it is cancelled
+                temp_objects.names.push(name.clone());
+                let object = self
+                    .compose_with(bucket, &req, &name, intermediate_parameters.clone())
+                    .await?;
+                intermediates.push(SourceObject {
+                    name,
+                    generation: Some(object.generation),
+                    object_preconditions: None,
+                });
+            }
+            source_objects = intermediates;
+            level += 1;
+        }
+        let req = ComposeRequest {
+            kind: "storage#composeRequest".to_string(),
+            source_objects,
+            destination: None,
+        };
+        self.compose_with(bucket, &req, destination_object, parameters)
+            .await
+    }
+
     /// Copy this object to the target bucket and path.
     /// ### Example
     /// ```no_run
@@ -502,6 +2682,45 @@ impl<'a> ObjectClient<'a> {
         object: &Object,
         destination_bucket: &str,
         path: &str,
+    ) -> crate::Result<Object> {
+        self.copy_with(object, destination_bucket, path, CopyParameters::default())
+            .await
+    }
+
+    /// Copy this object to the target bucket and path, using the provided `CopyParameters`. These
+    /// can be used to copy a specific generation of the object, or to set preconditions on the
+    /// source and destination objects.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{CopyParameters, Preconditions};
+    ///
+    /// let client = Client::default();
+    /// let obj1 = client.object().read("my_bucket", "file1").await?;
+    /// // copy this exact generation of obj1, but only if the snapshot does not exist yet
+    /// let parameters = CopyParameters {
+    ///     source_generation: Some(obj1.generation),
+    ///     preconditions: Preconditions {
+    ///         if_generation_match: Some(0),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// let snapshot = client
+    ///     .object()
+    ///     .copy_with(&obj1, "my_bucket", "file1-snapshot", parameters)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_with(
+        &self,
+        object: &Object,
+        destination_bucket: &str,
+        path: &str,
+        parameters: CopyParameters,
     ) -> crate::Result<Object> {
         use reqwest::header::CONTENT_LENGTH;
 
@@ -515,15 +2734,17 @@ impl<'a> ObjectClient<'a> {
         );
         let mut headers = self.0.get_headers().await?;
         headers.insert(CONTENT_LENGTH, "0".parse()?);
+        headers.extend(CustomerEncryptionKey::copy_headers(
+            parameters.encryption_key.as_ref(),
+            parameters.source_encryption_key.as_ref(),
+        )?);
         let result: GoogleResponse<Object> = self
             .0
-            .client
-            .post(&url)
-            .headers(headers)
-            .send()
+            .send(self.0.client.post(&url).headers(headers).query(&parameters))
             .await?
             .json()
             .await?;
+        self.forget(destination_bucket, path);
         match result {
             GoogleResponse::Success(s) => Ok(s),
             GoogleResponse::Error(e) => Err(e.into()),
@@ -535,8 +2756,8 @@ impl<'a> ObjectClient<'a> {
     /// ## Limitations
     /// This function does not yet support rewriting objects to another
     /// * Geographical Location,
-    /// * Encryption,
     /// * Storage class.
+    ///
     /// These limitations mean that for now, the rewrite and the copy methods do the same thing.
     /// ### Example
     /// ```no_run
@@ -557,6 +2778,50 @@ impl<'a> ObjectClient<'a> {
         object: &Object,
         destination_bucket: &str,
         path: &str,
+    ) -> crate::Result<Object> {
+        self.rewrite_with(
+            object,
+            destination_bucket,
+            path,
+            RewriteParameters::default(),
+        )
+        .await
+    }
+
+    /// Moves a file from the current location to the target bucket and path, in the same way as
+    /// `rewrite`, using the provided `RewriteParameters`. These can be used to rewrite a specific
+    /// generation of the object, or to set preconditions on the source and destination objects.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::{Preconditions, RewriteParameters};
+    ///
+    /// let client = Client::default();
+    /// let obj1 = client.object().read("my_bucket", "file1").await?;
+    /// // only succeeds if `file1` was not changed since it was read, and `file2` does not exist yet
+    /// let parameters = RewriteParameters {
+    ///     if_source_generation_match: Some(obj1.generation),
+    ///     preconditions: Preconditions {
+    ///         if_generation_match: Some(0),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// let obj2 = client
+    ///     .object()
+    ///     .rewrite_with(&obj1, "my_other_bucket", "file2", parameters)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rewrite_with(
+        &self,
+        object: &Object,
+        destination_bucket: &str,
+        path: &str,
+        parameters: RewriteParameters,
     ) -> crate::Result<Object> {
         use reqwest::header::CONTENT_LENGTH;
 
@@ -570,21 +2835,929 @@ impl<'a> ObjectClient<'a> {
         );
         let mut headers = self.0.get_headers().await?;
         headers.insert(CONTENT_LENGTH, "0".parse()?);
-        let s = self
+        headers.extend(CustomerEncryptionKey::copy_headers(
+            parameters.encryption_key.as_ref(),
+            parameters.source_encryption_key.as_ref(),
+        )?);
+        let result: GoogleResponse<RewriteResponse> = self
             .0
-            .client
+            .send(self.0.client.post(&url).headers(headers).query(&parameters))
+            .await?
+            .json()
+            .await?;
+        self.forget(destination_bucket, path);
+        match result {
+            GoogleResponse::Success(s) => Ok(s.resource),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+// Polls the inner stream ahead of its consumer, keeping at most `depth` items buffered. Because
+// the inner stream is polled again right after an item is taken, the request for the next item is
+// already in flight while the consumer processes the current one.
+struct Prefetch<S: Stream> {
+    inner: std::pin::Pin<Box<S>>,
+    buffer: std::collections::VecDeque<S::Item>,
+    depth: usize,
+    done: bool,
+}
+
+impl<S: Stream> Prefetch<S> {
+    fn new(inner: S, depth: usize) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            buffer: std::collections::VecDeque::new(),
+            depth: depth.max(1),
+            done: false,
+        }
+    }
+
+    fn fill(&mut self, cx: &mut std::task::Context<'_>) {
+        while !self.done && self.buffer.len() < self.depth {
+            match self.inner.as_mut().poll_next(cx) {
+                std::task::Poll::Ready(Some(item)) => self.buffer.push_back(item),
+                std::task::Poll::Ready(None) => self.done = true,
+                std::task::Poll::Pending => break,
+            }
+        }
+    }
+}
+
+// The inner stream is boxed and the buffered items are never pinned.
+impl<S: Stream> Unpin for Prefetch<S> {}
+
+impl<S: Stream> Stream for Prefetch<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.fill(cx);
+        match this.buffer.pop_front() {
+            Some(item) => {
+                this.fill(cx);
+                std::task::Poll::Ready(Some(item))
+            }
+            None if this.done => std::task::Poll::Ready(None),
+            None => std::task::Poll::Pending,
+        }
+    }
+}
+
+type ByteChunks = std::pin::Pin<Box<dyn Stream<Item = reqwest::Result<bytes::Bytes>> + Send>>;
+
+struct ResumeState {
+    client: reqwest::Client,
+    metrics_sink: Option<std::sync::Arc<dyn super::MetricsSink>>,
+    url: String,
+    headers: reqwest::header::HeaderMap,
+    chunks: ByteChunks,
+    offset: u64,
+    retries_left: u32,
+    done: bool,
+}
+
+// Returns `response` to a download if it succeeded, and the error that Google returned otherwise.
+// All downloads report a missing object in the same way.
+async fn checked_download(response: reqwest::Response) -> crate::Result<reqwest::Response> {
+    if response.status() == StatusCode::NOT_FOUND {
+        Err(crate::Error::Other(response.text().await?))
+    } else {
+        Ok(response.error_for_status()?)
+    }
+}
+
+// Streams the body of `response`. When the connection fails, the body is requested again from the
+// last received byte onwards, at most `max_retries` times.
+fn resumable_bytes(
+    client: reqwest::Client,
+    metrics_sink: Option<std::sync::Arc<dyn super::MetricsSink>>,
+    url: String,
+    headers: reqwest::header::HeaderMap,
+    response: reqwest::Response,
+    max_retries: u32,
+) -> impl Stream<Item = crate::Result<bytes::Bytes>> + Send + Unpin {
+    use futures_util::StreamExt;
+
+    let state = ResumeState {
+        client,
+        metrics_sink,
+        url,
+        headers,
+        chunks: Box::pin(response.bytes_stream()),
+        offset: 0,
+        retries_left: max_retries,
+        done: false,
+    };
+    Box::pin(stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        loop {
+            let mut error: crate::Error = match state.chunks.next().await {
+                Some(Ok(chunk)) => {
+                    state.offset += chunk.len() as u64;
+                    return Some((Ok(chunk), state));
+                }
+                Some(Err(e)) => e.into(),
+                None => return None,
+            };
+            loop {
+                if state.retries_left == 0 {
+                    state.done = true;
+                    return Some((Err(error), state));
+                }
+                state.retries_left -= 1;
+                let resumed = resume(
+                    &state.client,
+                    state.metrics_sink.as_deref(),
+                    &state.url,
+                    &state.headers,
+                    state.offset,
+                );
+                match resumed.await {
+                    Ok(Some(chunks)) => {
+                        state.chunks = chunks;
+                        break;
+                    }
+                    // everything had already been received when the connection failed
+                    Ok(None) => return None,
+                    Err(e) => error = e,
+                }
+            }
+        }
+    }))
+}
+
+async fn resume(
+    client: &reqwest::Client,
+    metrics_sink: Option<&dyn super::MetricsSink>,
+    url: &str,
+    headers: &reqwest::header::HeaderMap,
+    offset: u64,
+) -> crate::Result<Option<ByteChunks>> {
+    let request = client
+        .get(url)
+        .headers(headers.clone())
+        .header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    let response = super::send(client, metrics_sink, request).await?;
+    match response.status() {
+        StatusCode::PARTIAL_CONTENT => Ok(Some(Box::pin(response.bytes_stream()))),
+        StatusCode::RANGE_NOT_SATISFIABLE => Ok(None),
+        status => Err(crate::Error::Other(format!(
+            "expected a partial response when resuming the download, but got {}",
+            status
+        ))),
+    }
+}
+
+// The state of a resumable upload session while its content is being sent.
+struct ResumableUpload<'a> {
+    client: &'a reqwest::Client,
+    metrics_sink: Option<&'a dyn super::MetricsSink>,
+    session_uri: &'a str,
+    // sent along with every chunk, which is needed for customer-supplied encryption keys
+    headers: reqwest::header::HeaderMap,
+    length: Option<u64>,
+    retries_left: u32,
+}
+
+impl ResumableUpload<'_> {
+    // Sends `content` in chunks of `chunk_size` bytes. A chunk is kept until the session reports
+    // that it was received, so it can be sent again from the committed offset after a failure.
+    async fn send<S>(mut self, content: S, chunk_size: usize) -> crate::Result<Object>
+    where
+        S: Stream<Item = Result<bytes::Bytes, BoxError>> + Unpin,
+    {
+        use futures_util::StreamExt;
+
+        let mut content = content;
+        let mut buffer = bytes::BytesMut::new();
+        let mut offset = 0; // the number of bytes the session has committed
+        let mut skip = 0; // the number of upcoming bytes that the session already has
+        let mut ended = false;
+        loop {
+            while !ended && buffer.len() <= chunk_size {
+                match content.next().await {
+                    Some(Ok(chunk)) => {
+                        let skipped = skip.min(chunk.len());
+                        skip -= skipped;
+                        buffer.extend_from_slice(&chunk[skipped..]);
+                    }
+                    Some(Err(e)) => return Err(crate::Error::Other(e.to_string())),
+                    None => ended = true,
+                }
+            }
+            if skip > 0 {
+                return Err(crate::Error::Other(format!(
+                    "the upload session has received {} bytes, but the content is shorter",
+                    offset
+                )));
+            }
+            let (chunk, total) = if ended {
+                (buffer.len(), Some(offset + buffer.len() as u64))
+            } else {
+                (chunk_size, self.length)
+            };
+            let chunk = buffer.split_to(chunk).freeze();
+            match self.send_chunk(offset, chunk.clone(), total).await? {
+                Committed::Complete(object) => return Ok(*object),
+                Committed::Partial(committed) => {
+                    let end = offset + chunk.len() as u64;
+                    if committed < offset {
+                        return Err(crate::Error::Other(format!(
+                            "the upload session committed {} bytes, while {}-{} were sent",
+                            committed, offset, end
+                        )));
+                    }
+                    if committed > end {
+                        // an earlier, interrupted upload to the session got further than this one
+                        skip = (committed - end) as usize;
+                        let skipped = skip.min(buffer.len());
+                        skip -= skipped;
+                        bytes::Buf::advance(&mut buffer, skipped);
+                    } else {
+                        // put back what the session did not receive, to send it again
+                        let mut rest =
+                            bytes::BytesMut::from(&chunk[(committed - offset) as usize..]);
+                        rest.extend_from_slice(&buffer);
+                        buffer = rest;
+                    }
+                    offset = committed;
+                }
+            }
+        }
+    }
+
+    // Sends the bytes of `chunk`, which start at `offset`. When this fails transiently, the
+    // status of the session is queried instead, and the offset it has committed is returned.
+    async fn send_chunk(
+        &mut self,
+        offset: u64,
+        chunk: bytes::Bytes,
+        total: Option<u64>,
+    ) -> crate::Result<Committed> {
+        let total = total.map_or("*".to_string(), |total| total.to_string());
+        let mut content_range = if chunk.is_empty() {
+            format!("bytes */{}", total)
+        } else {
+            format!(
+                "bytes {}-{}/{}",
+                offset,
+                offset + chunk.len() as u64 - 1,
+                total
+            )
+        };
+        let mut body = chunk;
+        let mut attempt = 0;
+        loop {
+            let request = self
+                .client
+                .put(self.session_uri)
+                .headers(self.headers.clone())
+                .header(reqwest::header::CONTENT_RANGE, &content_range)
+                .body(body.clone());
+            let error = match super::send(self.client, self.metrics_sink, request).await {
+                Ok(response) if !is_transient(response.status()) => {
+                    return Committed::from_response(response).await
+                }
+                Ok(response) => crate::Error::new(&response.text().await?),
+                Err(e) => e.into(),
+            };
+            if self.retries_left == 0 {
+                return Err(error);
+            }
+            self.retries_left -= 1;
+            tokio::time::sleep(std::time::Duration::from_millis(100 << attempt.min(6))).await;
+            attempt += 1;
+            // ask the session what it has received
+            content_range = format!("bytes */{}", total);
+            body = bytes::Bytes::new();
+        }
+    }
+}
+
+// The intermediate objects of `compose_all`. They are deleted with `delete_all` when the
+// composition is done, or in the background when it is dropped before that.
+struct TempObjects<'a> {
+    client: &'a super::Client,
+    bucket: &'a str,
+    names: Vec<String>,
+}
+
+impl TempObjects<'_> {
+    // Deletes the objects, and returns the names of the ones that could not be deleted. Objects
+    // that do not exist, because the request that would have created them failed, are skipped.
+    async fn delete_all(&mut self) -> Vec<String> {
+        let mut leftover = Vec::new();
+        for name in std::mem::take(&mut self.names) {
+            match ObjectClient(self.client).delete(self.bucket, &name).await {
+                Ok(()) => {}
+                Err(crate::Error::Google(e)) if e.error.code == StatusCode::NOT_FOUND => {}
+                Err(e) => {
+                    log::warn!("failed to delete intermediate object {}: {}", name, e);
+                    leftover.push(name);
+                }
+            }
+        }
+        leftover
+    }
+}
+
+impl Drop for TempObjects<'_> {
+    fn drop(&mut self) {
+        if self.names.is_empty() {
+            return;
+        }
+        let names = std::mem::take(&mut self.names);
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => runtime,
+            Err(_) => {
+                log::warn!("failed to delete intermediate objects {}", names.join(", "));
+                return;
+            }
+        };
+        let client = self.client.client.clone();
+        let token_cache = self.client.token_cache.clone();
+        let url = format!("{}/b/{}/o", crate::BASE_URL, percent_encode(self.bucket));
+        runtime.spawn(async move {
+            for name in names {
+                let mut request = client.delete(format!("{}/{}", url, percent_encode(&name)));
+                if let Ok(token) = token_cache.get(&client).await {
+                    if !token.is_empty() {
+                        request = request.bearer_auth(token);
+                    }
+                }
+                let response = super::send(&client, None, request).await;
+                if !matches!(response, Ok(ref response) if response.status().is_success()) {
+                    log::warn!("failed to delete intermediate object {}", name);
+                }
+            }
+        });
+    }
+}
+
+// How much of a resumable upload a session has received.
+enum Committed {
+    Partial(u64),
+    Complete(Box<Object>),
+}
+
+impl Committed {
+    async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(Self::Complete(serde_json::from_str(
+                &response.text().await?,
+            )?)),
+            StatusCode::PERMANENT_REDIRECT => {
+                // `Range: bytes=0-{last}`, which is absent when nothing was received yet
+                let range = response.headers().get(reqwest::header::RANGE);
+                let last = range
+                    .and_then(|range| range.to_str().ok())
+                    .and_then(|range| range.strip_prefix("bytes=0-"))
+                    .map(str::parse::<u64>);
+                match last {
+                    None if range.is_none() => Ok(Self::Partial(0)),
+                    Some(Ok(last)) => Ok(Self::Partial(last + 1)),
+                    _ => Err(crate::Error::Other(format!(
+                        "the upload session reported an invalid range: {:?}",
+                        range
+                    ))),
+                }
+            }
+            _ => Err(crate::Error::new(&response.text().await?)),
+        }
+    }
+}
+
+// Whether a request that failed with `status` may succeed when it is sent again.
+fn is_transient(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status == StatusCode::REQUEST_TIMEOUT
+}
+
+// Records how far the content of a streamed upload got.
+#[derive(Debug, Default)]
+struct UploadProgress {
+    sent: std::sync::atomic::AtomicU64,
+    failed: std::sync::atomic::AtomicBool,
+}
+
+fn track_upload<S>(
+    content: S,
+    progress: std::sync::Arc<UploadProgress>,
+) -> impl Stream<Item = Result<bytes::Bytes, BoxError>> + Send + Sync + 'static
+where
+    S: Stream<Item = Result<bytes::Bytes, BoxError>> + Send + Sync + 'static,
+{
+    use futures_util::StreamExt;
+    use std::sync::atomic::Ordering;
+
+    content.inspect(move |chunk| match chunk {
+        Ok(chunk) => {
+            progress
+                .sent
+                .fetch_add(chunk.len() as u64, Ordering::SeqCst);
+        }
+        Err(_) => progress.failed.store(true, Ordering::SeqCst),
+    })
+}
+
+// Kept apart from the upload methods, whose `Bytes: From<S::Ok>` bound confuses inference here.
+fn media_body<S>(content: S) -> reqwest::Body
+where
+    S: Stream<Item = Result<bytes::Bytes, BoxError>> + Send + Sync + 'static,
+{
+    reqwest::Body::wrap_stream(content)
+}
+
+// The headers that start a resumable upload session, which declare the content that will follow.
+fn resumable_session_headers(
+    mime_type: &str,
+    length: Option<u64>,
+) -> crate::Result<reqwest::header::HeaderMap> {
+    use reqwest::header::{HeaderMap, CONTENT_TYPE};
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, "application/json; charset=UTF-8".parse()?);
+    headers.insert("X-Upload-Content-Type", mime_type.parse()?);
+    if let Some(length) = length {
+        headers.insert("X-Upload-Content-Length", length.into());
+    }
+    Ok(headers)
+}
+
+// The parts of a multipart upload that precede and follow the contents of the object.
+fn multipart_delimiters(
+    metadata: &serde_json::Value,
+    mime_type: &str,
+) -> crate::Result<(String, String)> {
+    let head = format!(
+        "--{boundary}\r\n\
+        Content-Type: application/json; charset=UTF-8\r\n\r\n\
+        {metadata}\r\n\
+        --{boundary}\r\n\
+        Content-Type: {mime_type}\r\n\r\n",
+        boundary = MULTIPART_BOUNDARY,
+        metadata = serde_json::to_string(metadata)?,
+        mime_type = mime_type,
+    );
+    let tail = format!("\r\n--{}--\r\n", MULTIPART_BOUNDARY);
+    Ok((head, tail))
+}
+
+fn multipart_content_type() -> String {
+    format!("multipart/related; boundary={}", MULTIPART_BOUNDARY)
+}
+
+// A page of a listing in which only the sizes of the objects were requested.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SizePage {
+    #[serde(default)]
+    items: Vec<ObjectSize>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ObjectSize {
+    #[serde(deserialize_with = "crate::from_str")]
+    size: u64,
+}
+
+// Adds the objects in `page` to `usage`, and returns the token of the next page, if any.
+fn add_usage(usage: &mut Usage, page: SizePage) -> Option<String> {
+    usage.count += page.items.len() as u64;
+    usage.total_bytes += page.items.iter().map(|item| item.size).sum::<u64>();
+    page.next_page_token
+}
+
+// The length of a multipart body whose content is `length` bytes long.
+fn multipart_length(head: &str, length: u64, tail: &str) -> u64 {
+    head.len() as u64 + length + tail.len() as u64
+}
+
+// Surrounds the contents of a multipart upload with the parts that precede and follow it.
+fn multipart_body<S>(head: String, content: S, tail: String) -> reqwest::Body
+where
+    S: Stream<Item = Result<bytes::Bytes, BoxError>> + Send + Sync + 'static,
+{
+    use futures_util::StreamExt;
+
+    let body = stream::once(async move { Ok(bytes::Bytes::from(head)) })
+        .chain(content)
+        .chain(stream::once(async move { Ok(bytes::Bytes::from(tail)) }));
+    reqwest::Body::wrap_stream(body)
+}
+
+// Lists the files in `dir` and all of its subdirectories.
+async fn files_in(dir: &std::path::Path) -> std::io::Result<Vec<std::path::PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let file_type = tokio::fs::symlink_metadata(&path).await?.file_type();
+            if file_type.is_dir() {
+                dirs.push(path);
+            } else if file_type.is_symlink() {
+                // symlinked directories are skipped, since they may link back to their parent
+                let target_is_dir = tokio::fs::metadata(&path)
+                    .await
+                    .map(|metadata| metadata.is_dir())
+                    .unwrap_or(false);
+                if !target_is_dir {
+                    files.push(path);
+                }
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    Ok(files)
+}
+
+// The name of the object that the file at `path` in `dir` is uploaded to.
+fn object_name(prefix: &str, dir: &std::path::Path, path: &std::path::Path) -> String {
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    let relative: Vec<_> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect();
+    format!("{}{}", prefix, relative.join("/"))
+}
+
+// Infers the mime type of a file from its extension, for the file types that are common on
+// websites.
+fn mime_type(path: &std::path::Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("html") | Some("htm") => "text/html",
+        Some("css") => "text/css",
+        Some("js") | Some("mjs") => "text/javascript",
+        Some("json") | Some("map") => "application/json",
+        Some("txt") => "text/plain",
+        Some("csv") => "text/csv",
+        Some("xml") => "application/xml",
+        Some("pdf") => "application/pdf",
+        Some("wasm") => "application/wasm",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("ico") => "image/vnd.microsoft.icon",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        Some("ttf") => "font/ttf",
+        Some("mp4") => "video/mp4",
+        Some("webm") => "video/webm",
+        Some("mp3") => "audio/mpeg",
+        _ => "application/octet-stream",
+    }
+}
+
+fn gzip(data: &[u8]) -> crate::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::TryStreamExt;
+    use std::io::{Read, Write};
+
+    // Reads a request from `stream`, and returns its head in lowercase.
+    fn read_request(stream: &mut std::net::TcpStream) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+        String::from_utf8(request).unwrap().to_lowercase()
+    }
+
+    #[tokio::test]
+    async fn upload_dir_names() -> Result<(), Box<dyn std::error::Error>> {
+        let dir =
+            std::env::temp_dir().join(format!("cloud-storage-upload-dir-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("assets/img"))?;
+        std::fs::write(dir.join("index.html"), "<html></html>")?;
+        std::fs::write(dir.join("assets/img/logo.PNG"), [0])?;
+        // a link back to the parent directory must not make the walk loop forever
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&dir, dir.join("assets/parent"))?;
+
+        let mut files = files_in(&dir).await?;
+        files.sort();
+        let names: Vec<_> = files
+            .iter()
+            .map(|path| (object_name("site/", &dir, path), mime_type(path)))
+            .collect();
+        std::fs::remove_dir_all(&dir)?;
+        assert_eq!(
+            names,
+            vec![
+                ("site/assets/img/logo.PNG".to_string(), "image/png"),
+                ("site/index.html".to_string(), "text/html"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resumable_session_declares_content() -> Result<(), Box<dyn std::error::Error>> {
+        let headers = resumable_session_headers("image/png", Some(1024))?;
+        assert_eq!(headers["x-upload-content-type"], "image/png");
+        assert_eq!(headers["x-upload-content-length"], "1024");
+        assert_eq!(headers["content-type"], "application/json; charset=UTF-8");
+
+        let headers = resumable_session_headers("text/plain", None)?;
+        assert_eq!(headers["x-upload-content-type"], "text/plain");
+        assert!(!headers.contains_key("x-upload-content-length"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prefetch_reads_ahead() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let polled = AtomicUsize::new(0);
+        let inner = stream::iter(0..5).inspect(|_| {
+            polled.fetch_add(1, Ordering::SeqCst);
+        });
+        let mut prefetch = Prefetch::new(inner, 2);
+        assert_eq!(prefetch.next().await, Some(0));
+        assert_eq!(polled.load(Ordering::SeqCst), 3);
+        assert_eq!(prefetch.collect::<Vec<_>>().await, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn usage_adds_up_pages() -> Result<(), Box<dyn std::error::Error>> {
+        let mut usage = Usage::default();
+        let page = r#"{
+            "items": [{ "size": "10" }, { "size": "2048" }],
+            "nextPageToken": "CgRsb2dz"
+        }"#;
+        let next = add_usage(&mut usage, serde_json::from_str(page)?);
+        assert_eq!(next.as_deref(), Some("CgRsb2dz"));
+        // the last page of an empty prefix has no items at all
+        let next = add_usage(&mut usage, serde_json::from_str("{}")?);
+        assert_eq!(next, None);
+        assert_eq!(
+            usage,
+            Usage {
+                count: 2,
+                total_bytes: 2058
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn multipart_body_is_sized() -> Result<(), Box<dyn std::error::Error>> {
+        use reqwest::header::CONTENT_LENGTH;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/upload", listener.local_addr()?);
+        let metadata = serde_json::json!({ "metadata": { "photographer": "Alice" } });
+        let (head, tail) = multipart_delimiters(&metadata, "text/plain")?;
+        let length = multipart_length(&head, 10, &tail);
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            let body_start = loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break i + 4;
+                }
+            };
+            while request.len() < body_start + length as usize {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let head = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+            assert!(head.contains(&format!("content-length: {}", length)));
+            assert!(!head.contains("transfer-encoding"), "{}", head);
+            let body = String::from_utf8(request[body_start..].to_vec()).unwrap();
+            assert_eq!(body.len() as u64, length);
+            assert!(body.contains(r#"{"metadata":{"photographer":"Alice"}}"#));
+            assert!(body.contains("0123456789"));
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .unwrap();
+        });
+
+        let content = stream::iter(vec![
+            Ok(bytes::Bytes::from_static(b"01234")),
+            Ok(bytes::Bytes::from_static(b"56789")),
+        ]);
+        let response = reqwest::Client::new()
             .post(&url)
-            .headers(headers)
+            .header(CONTENT_LENGTH, length)
+            .body(multipart_body(head, content, tail))
             .send()
-            .await?
-            .text()
             .await?;
+        assert_eq!(response.status(), 200);
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resumable_bytes_resumes() -> Result<(), Box<dyn std::error::Error>> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/file", listener.local_addr()?);
+        let server = std::thread::spawn(move || {
+            // the first connection drops halfway through the body
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 10\r\n\r\n01234")
+                .unwrap();
+            drop(stream);
+            // the second connection must continue where the first one stopped
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream);
+            assert!(request.contains("range: bytes=5-"));
+            stream
+                .write_all(b"HTTP/1.1 206 Partial Content\r\ncontent-length: 5\r\n\r\n56789")
+                .unwrap();
+        });
+
+        let client = reqwest::Client::new();
+        let response = client.get(&url).send().await?;
+        let headers = reqwest::header::HeaderMap::new();
+        let bytes: Vec<bytes::Bytes> = resumable_bytes(client, None, url, headers, response, 1)
+            .try_collect()
+            .await?;
+        assert_eq!(bytes.concat(), b"0123456789");
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resumable_upload_recovers() -> Result<(), Box<dyn std::error::Error>> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let session_uri = format!("http://{}/session", listener.local_addr()?);
+        let server = std::thread::spawn(move || {
+            let accept = |expected: &str, body_len: usize, response: &str| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                // the head and the chunk may arrive in the same read
+                while request.len() < body_len + 4
+                    || !request[..request.len() - body_len].ends_with(b"\r\n\r\n")
+                {
+                    let n = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8(request).unwrap().to_lowercase();
+                assert!(request.contains(expected), "{}", request);
+                stream.write_all(response.as_bytes()).unwrap();
+            };
+            let resume_incomplete = |range: &str| {
+                format!(
+                    "HTTP/1.1 308 Resume Incomplete\r\nrange: {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    range
+                )
+            };
+            accept(
+                "content-range: bytes 0-3/*",
+                4,
+                &resume_incomplete("bytes=0-3"),
+            );
+            // the connection drops before the second chunk is acknowledged
+            accept("content-range: bytes 4-7/*", 4, "");
+            // the session has received only part of it
+            accept(
+                "content-range: bytes */*",
+                0,
+                &resume_incomplete("bytes=0-5"),
+            );
+            let mut object = crate::test_object_response("my-bucket", "file");
+            object["size"] = "10".into();
+            let object = object.to_string();
+            let created = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                object.len(),
+                object
+            );
+            accept("content-range: bytes 6-9/10", 4, &created);
+        });
 
-        let result: RewriteResponse = serde_json::from_str(&s).unwrap();
-        Ok(result.resource)
-        // match result {
-        // GoogleResponse::Success(s) => Ok(s.resource),
-        // GoogleResponse::Error(e) => Err(e.into()),
-        // }
+        let client = reqwest::Client::new();
+        let upload = ResumableUpload {
+            client: &client,
+            metrics_sink: None,
+            session_uri: &session_uri,
+            headers: reqwest::header::HeaderMap::new(),
+            length: None,
+            retries_left: 1,
+        };
+        let content = stream::iter(vec![Ok(bytes::Bytes::from_static(b"0123456789"))]);
+        let object = upload.send(content, 4).await?;
+        assert_eq!(object.size, 10);
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resumable_upload_continues_session() -> Result<(), Box<dyn std::error::Error>> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let session_uri = format!("http://{}/session", listener.local_addr()?);
+        let server = std::thread::spawn(move || {
+            let accept = |expected: &str, body: &str, response: &str| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(format!("\r\n\r\n{}", body).as_bytes()) {
+                    let n = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8(request).unwrap().to_lowercase();
+                assert!(request.contains(expected), "{}", request);
+                stream.write_all(response.as_bytes()).unwrap();
+            };
+            // an earlier upload to the session that was dropped got up to byte 6
+            accept(
+                "content-range: bytes 0-3/*",
+                "0123",
+                "HTTP/1.1 308 Resume Incomplete\r\nrange: bytes=0-5\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+            );
+            let mut object = crate::test_object_response("my-bucket", "file");
+            object["size"] = "10".into();
+            let object = object.to_string();
+            let created = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                object.len(),
+                object
+            );
+            // only the bytes that the session does not have yet are sent
+            accept("content-range: bytes 6-9/10", "6789", &created);
+        });
+
+        let client = reqwest::Client::new();
+        let upload = ResumableUpload {
+            client: &client,
+            metrics_sink: None,
+            session_uri: &session_uri,
+            headers: reqwest::header::HeaderMap::new(),
+            length: None,
+            retries_left: 0,
+        };
+        let content = stream::iter(vec![Ok(bytes::Bytes::from_static(b"0123456789"))]);
+        let object = upload.send(content, 4).await?;
+        assert_eq!(object.size, 10);
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn abort_resumable_session() -> Result<(), Box<dyn std::error::Error>> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let session_uri = format!("http://{}/session", listener.local_addr()?);
+        let server = std::thread::spawn(move || {
+            for response in &["499 Client Closed Request", "400 Bad Request"] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 1024];
+                let n = stream.read(&mut request).unwrap();
+                assert!(request[..n].starts_with(b"DELETE /session "));
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    response
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        let client = crate::Client::anonymous();
+        client
+            .object()
+            .abort_resumable_session(&session_uri)
+            .await?;
+        assert!(client
+            .object()
+            .abort_resumable_session(&session_uri)
+            .await
+            .is_err());
+        server.join().unwrap();
+        Ok(())
     }
 }
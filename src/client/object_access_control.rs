@@ -23,19 +23,48 @@ impl<'a> ObjectAccessControlClient<'a> {
         object: &str,
         new_object_access_control: &NewObjectAccessControl,
     ) -> crate::Result<ObjectAccessControl> {
-        let url = format!(
-            "{}/b/{}/o/{}/acl",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(object),
-        );
+        self.create_in(bucket, object, None, new_object_access_control)
+            .await
+    }
+
+    /// Creates a new ACL entry on the version of the specified `object` with the specified
+    /// `generation`. In buckets with object versioning enabled, every version has its own ACL, and
+    /// `create` only changes the ACL of the live version.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform
+    /// bucket-level access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to
+    /// control access instead.
+    pub async fn create_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+        new_object_access_control: &NewObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.create_in(bucket, object, Some(generation), new_object_access_control)
+            .await
+    }
+
+    // Creates an ACL entry on the object, or on one of its versions.
+    async fn create_in(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: Option<i64>,
+        new_object_access_control: &NewObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
+        let url = acl_url(bucket, object);
         let result: GoogleResponse<ObjectAccessControl> = self
             .0
-            .client
-            .post(&url)
-            .headers(self.0.get_headers().await?)
-            .json(new_object_access_control)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .post(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&generation_query(generation))
+                    .json(new_object_access_control),
+            )
             .await?
             .json()
             .await?;
@@ -56,18 +85,42 @@ impl<'a> ObjectAccessControlClient<'a> {
         bucket: &str,
         object: &str,
     ) -> crate::Result<Vec<ObjectAccessControl>> {
-        let url = format!(
-            "{}/b/{}/o/{}/acl",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(object),
-        );
+        self.list_in(bucket, object, None).await
+    }
+
+    /// Retrieves `ACL` entries on the version of the specified object with the specified
+    /// `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub async fn list_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        self.list_in(bucket, object, Some(generation)).await
+    }
+
+    // Retrieves the ACL entries of the object, or of one of its versions.
+    async fn list_in(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: Option<i64>,
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        let url = acl_url(bucket, object);
         let result: GoogleResponse<ListResponse<ObjectAccessControl>> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&generation_query(generation)),
+            )
             .await?
             .json()
             .await?;
@@ -77,6 +130,88 @@ impl<'a> ObjectAccessControlClient<'a> {
         }
     }
 
+    /// Retrieves the complete `ACL` of the specified object, as part of the object resource. This
+    /// returns the same entries as `list`, and is the counterpart of `set_all`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub async fn list_all(
+        &self,
+        bucket: &str,
+        object: &str,
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        let result: GoogleResponse<ObjectAcl> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .get(object_url(bucket, object))
+                    .headers(self.0.get_headers().await?)
+                    .query(&ACL_ONLY),
+            )
+            .await?
+            .json()
+            .await?;
+        match result {
+            GoogleResponse::Success(s) => Ok(s.acl),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
+    /// Replaces the complete `ACL` of the specified object with `acl` in a single request, and
+    /// returns the resulting entries. Entities that are not part of `acl` lose their access. Unlike
+    /// a sequence of `create` and `delete` calls, this can not leave the object with only part of
+    /// the new `ACL` when it is interrupted.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket_access_control::Entity;
+    /// use cloud_storage::object_access_control::{NewObjectAccessControl, Role};
+    ///
+    /// let client = Client::default();
+    /// let acl = vec![
+    ///     NewObjectAccessControl { entity: Entity::user("liz@example.com"), role: Role::Owner },
+    ///     NewObjectAccessControl { entity: Entity::AllUsers, role: Role::Reader },
+    /// ];
+    /// client.object_access_control().set_all("my_bucket", "my_object", &acl).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_all(
+        &self,
+        bucket: &str,
+        object: &str,
+        acl: &[NewObjectAccessControl],
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        let result: GoogleResponse<ObjectAcl> = self
+            .0
+            .send(
+                self.0
+                    .client
+                    .patch(object_url(bucket, object))
+                    .headers(self.0.get_headers().await?)
+                    .query(&ACL_ONLY)
+                    .json(&serde_json::json!({ "acl": acl })),
+            )
+            .await?
+            .json()
+            .await?;
+        self.0.object().forget(bucket, object);
+        match result {
+            GoogleResponse::Success(s) => Ok(s.acl),
+            GoogleResponse::Error(e) => Err(e.into()),
+        }
+    }
+
     /// Returns the `ACL` entry for the specified entity on the specified bucket.
     ///
     /// ### Important
@@ -89,19 +224,44 @@ impl<'a> ObjectAccessControlClient<'a> {
         object: &str,
         entity: &Entity,
     ) -> crate::Result<ObjectAccessControl> {
-        let url = format!(
-            "{}/b/{}/o/{}/acl/{}",
-            crate::BASE_URL,
-            percent_encode(bucket),
-            percent_encode(object),
-            percent_encode(&entity.to_string())
-        );
+        self.read_in(bucket, object, None, entity).await
+    }
+
+    /// Returns the `ACL` entry for the specified entity on the version of the specified object
+    /// with the specified `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub async fn read_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+        entity: &Entity,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.read_in(bucket, object, Some(generation), entity).await
+    }
+
+    // Returns the ACL entry for `entity` on the object, or on one of its versions.
+    async fn read_in(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: Option<i64>,
+        entity: &Entity,
+    ) -> crate::Result<ObjectAccessControl> {
+        let url = entry_url(bucket, object, entity);
         let result: GoogleResponse<ObjectAccessControl> = self
             .0
-            .client
-            .get(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.0
+                    .client
+                    .get(&url)
+                    .headers(self.0.get_headers().await?)
+                    .query(&generation_query(generation)),
+            )
             .await?
             .json()
             .await?;
@@ -111,7 +271,7 @@ impl<'a> ObjectAccessControlClient<'a> {
         }
     }
 
-    /// Updates an ACL entry on the specified object.
+    /// Updates an ACL entry on the live version of the specified object.
     ///
     /// ### Important
     /// Important: This method fails with a 400 Bad Request response for buckets with uniform
@@ -121,20 +281,37 @@ impl<'a> ObjectAccessControlClient<'a> {
         &self,
         object_access_control: &ObjectAccessControl,
     ) -> crate::Result<ObjectAccessControl> {
-        let url = format!(
-            "{}/b/{}/o/{}/acl/{}",
-            crate::BASE_URL,
-            percent_encode(&object_access_control.bucket),
-            percent_encode(&object_access_control.object),
-            percent_encode(&object_access_control.entity.to_string()),
-        );
+        self.update_in(None, object_access_control).await
+    }
+
+    /// Updates an ACL entry on the version of the specified object with the specified
+    /// `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub async fn update_for_generation(
+        &self,
+        generation: i64,
+        object_access_control: &ObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.update_in(Some(generation), object_access_control)
+            .await
+    }
+
+    // Updates an ACL entry on the object, or on one of its versions.
+    async fn update_in(
+        &self,
+        generation: Option<i64>,
+        object_access_control: &ObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
         let result: GoogleResponse<ObjectAccessControl> = self
             .0
-            .client
-            .put(&url)
-            .headers(self.0.get_headers().await?)
-            .json(object_access_control)
-            .send()
+            .send(
+                self.update_request(generation, object_access_control)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?
             .json()
             .await?;
@@ -144,31 +321,170 @@ impl<'a> ObjectAccessControlClient<'a> {
         }
     }
 
-    /// Permanently deletes the ACL entry for the specified entity on the specified object.
+    // The request that updates an ACL entry, without its authorization headers.
+    fn update_request(
+        &self,
+        generation: Option<i64>,
+        object_access_control: &ObjectAccessControl,
+    ) -> reqwest::RequestBuilder {
+        let url = entry_url(
+            &object_access_control.bucket,
+            &object_access_control.object,
+            &object_access_control.entity,
+        );
+        self.0
+            .client
+            .put(&url)
+            .query(&generation_query(generation))
+            .json(object_access_control)
+    }
+
+    /// Permanently deletes the ACL entry for the specified entity on the live version of the
+    /// specified object.
     ///
     /// ### Important
     /// Important: This method fails with a 400 Bad Request response for buckets with uniform
     /// bucket-level access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to
     /// control access instead.
     pub async fn delete(&self, object_access_control: ObjectAccessControl) -> crate::Result<()> {
-        let url = format!(
-            "{}/b/{}/o/{}/acl/{}",
-            crate::BASE_URL,
-            percent_encode(&object_access_control.bucket),
-            percent_encode(&object_access_control.object),
-            percent_encode(&object_access_control.entity.to_string()),
-        );
+        self.delete_in(None, object_access_control).await
+    }
+
+    /// Permanently deletes the ACL entry for the specified entity on the version of the specified
+    /// object with the specified `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub async fn delete_for_generation(
+        &self,
+        generation: i64,
+        object_access_control: ObjectAccessControl,
+    ) -> crate::Result<()> {
+        self.delete_in(Some(generation), object_access_control)
+            .await
+    }
+
+    // Deletes an ACL entry from the object, or from one of its versions.
+    async fn delete_in(
+        &self,
+        generation: Option<i64>,
+        object_access_control: ObjectAccessControl,
+    ) -> crate::Result<()> {
         let response = self
             .0
-            .client
-            .delete(&url)
-            .headers(self.0.get_headers().await?)
-            .send()
+            .send(
+                self.delete_request(generation, &object_access_control)
+                    .headers(self.0.get_headers().await?),
+            )
             .await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(crate::Error::Google(response.json().await?))
+            Err(crate::Error::from(
+                response.json::<crate::error::GoogleErrorResponse>().await?,
+            ))
         }
     }
+
+    // The request that deletes an ACL entry, without its authorization headers.
+    fn delete_request(
+        &self,
+        generation: Option<i64>,
+        object_access_control: &ObjectAccessControl,
+    ) -> reqwest::RequestBuilder {
+        let url = entry_url(
+            &object_access_control.bucket,
+            &object_access_control.object,
+            &object_access_control.entity,
+        );
+        self.0
+            .client
+            .delete(&url)
+            .query(&generation_query(generation))
+    }
+}
+
+// The query parameters that limit an object resource to its access control list.
+const ACL_ONLY: [(&str, &str); 2] = [("projection", "full"), ("fields", "acl")];
+
+// The access control list of an object, as part of the object resource.
+#[derive(Debug, serde::Deserialize)]
+struct ObjectAcl {
+    #[serde(default)]
+    acl: Vec<ObjectAccessControl>,
+}
+
+// The url of an object.
+fn object_url(bucket: &str, object: &str) -> String {
+    format!(
+        "{}/b/{}/o/{}",
+        crate::BASE_URL,
+        percent_encode(bucket),
+        percent_encode(object),
+    )
+}
+
+// The query parameters that select the version of an object with `generation`, or the live version
+// when it is `None`.
+fn generation_query(generation: Option<i64>) -> [(&'static str, Option<i64>); 1] {
+    [("generation", generation)]
+}
+
+// The url of the access control list of an object.
+fn acl_url(bucket: &str, object: &str) -> String {
+    format!("{}/acl", object_url(bucket, object))
+}
+
+// The url of the entry for `entity` in the access control list of an object.
+fn entry_url(bucket: &str, object: &str, entity: &Entity) -> String {
+    format!(
+        "{}/{}",
+        acl_url(bucket, object),
+        percent_encode(&entity.to_string())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generation_in_query() {
+        assert_eq!(crate::query_of(&generation_query(None)), None);
+        assert_eq!(
+            crate::query_of(&generation_query(Some(1617000000000000))).as_deref(),
+            Some("generation=1617000000000000")
+        );
+    }
+
+    #[test]
+    fn generation_of_entry_is_not_sent() -> Result<(), Box<dyn std::error::Error>> {
+        // Google fills in the generation of every entry it returns
+        let entry: ObjectAccessControl = serde_json::from_value(serde_json::json!({
+            "kind": "storage#objectAccessControl",
+            "id": "my-bucket/my-object/1617000000000000/allUsers",
+            "selfLink": "https://www.googleapis.com/storage/v1/b/my-bucket/o/my-object/acl/allUsers",
+            "bucket": "my-bucket",
+            "object": "my-object",
+            "generation": "1617000000000000",
+            "entity": "allUsers",
+            "role": "READER",
+            "etag": "CIDk5qq8iu8CEAI="
+        }))?;
+        let client = crate::Client::anonymous();
+        let acl = client.object_access_control();
+
+        let request = acl.update_request(None, &entry).build()?;
+        assert_eq!(request.url().query(), None);
+        let request = acl.delete_request(None, &entry).build()?;
+        assert_eq!(request.url().query(), None);
+
+        let request = acl.update_request(Some(1617000000000000), &entry).build()?;
+        assert_eq!(request.url().query(), Some("generation=1617000000000000"));
+        let request = acl.delete_request(Some(1617000000000000), &entry).build()?;
+        assert_eq!(request.url().query(), Some("generation=1617000000000000"));
+        Ok(())
+    }
 }
@@ -0,0 +1,252 @@
+use std::{
+    io::{self, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{future::BoxFuture, Future, Stream};
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
+type ByteChunks = Pin<Box<dyn Stream<Item = reqwest::Result<bytes::Bytes>> + Send>>;
+
+/// Gives random access to the content of an object, through `AsyncRead` and `AsyncSeek`. Nothing
+/// is downloaded until the content is read. A read starts a range request at the current position,
+/// which is reused by the reads that follow it, until the reader is moved to another position by
+/// a seek. No content is cached.
+///
+/// A `RangeReader` is obtained using [`ObjectClient::open_range_reader`](super::ObjectClient::open_range_reader).
+pub struct RangeReader<'a> {
+    client: &'a super::Client,
+    url: String,
+    size: u64,
+    position: u64,
+    state: State<'a>,
+}
+
+enum State<'a> {
+    Idle,
+    Connecting(BoxFuture<'a, crate::Result<ByteChunks>>),
+    Streaming(ByteChunks, bytes::Bytes),
+}
+
+impl std::fmt::Debug for RangeReader<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangeReader")
+            .field("url", &self.url)
+            .field("size", &self.size)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl<'a> RangeReader<'a> {
+    pub(super) fn new(client: &'a super::Client, url: String, size: u64) -> Self {
+        Self {
+            client,
+            url,
+            size,
+            position: 0,
+            state: State::Idle,
+        }
+    }
+
+    /// Returns the size of the object in bytes.
+    pub fn len(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns whether the object is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn connect(&self) -> BoxFuture<'a, crate::Result<ByteChunks>> {
+        let client = self.client;
+        let url = self.url.clone();
+        let position = self.position;
+        Box::pin(async move {
+            let request = client
+                .client
+                .get(&url)
+                .headers(client.get_headers().await?)
+                .header(reqwest::header::RANGE, format!("bytes={}-", position));
+            let response = client.send(request).await?.error_for_status()?;
+            let chunks: ByteChunks = Box::pin(response.bytes_stream());
+            Ok(chunks)
+        })
+    }
+}
+
+impl AsyncRead for RangeReader<'_> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.position >= this.size || buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            match this.state {
+                State::Idle => this.state = State::Connecting(this.connect()),
+                State::Connecting(ref mut connecting) => match Pin::new(connecting).poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(chunks)) => {
+                        this.state = State::Streaming(chunks, bytes::Bytes::new())
+                    }
+                    Poll::Ready(Err(e)) => {
+                        this.state = State::Idle;
+                        return Poll::Ready(Err(io::Error::other(e)));
+                    }
+                },
+                State::Streaming(ref mut chunks, ref mut leftover) => {
+                    if !leftover.is_empty() {
+                        let n = leftover.len().min(buf.remaining());
+                        buf.put_slice(&leftover.split_to(n));
+                        this.position += n as u64;
+                        return Poll::Ready(Ok(()));
+                    }
+                    match chunks.as_mut().poll_next(cx) {
+                        Poll::Pending => return Poll::Pending,
+                        Poll::Ready(Some(Ok(chunk))) => *leftover = chunk,
+                        Poll::Ready(Some(Err(e))) => {
+                            this.state = State::Idle;
+                            return Poll::Ready(Err(io::Error::other(e)));
+                        }
+                        Poll::Ready(None) => {
+                            this.state = State::Idle;
+                            return Poll::Ready(Err(io::Error::new(
+                                io::ErrorKind::UnexpectedEof,
+                                "the object ended before its reported size",
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl AsyncSeek for RangeReader<'_> {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        let position = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => offset_by(this.size, offset),
+            SeekFrom::Current(offset) => offset_by(this.position, offset),
+        };
+        let position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        if position != this.position {
+            // the open response continues at the old position
+            this.state = State::Idle;
+            this.position = position;
+        }
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+fn offset_by(base: u64, offset: i64) -> Option<u64> {
+    if offset < 0 {
+        base.checked_sub(offset.unsigned_abs())
+    } else {
+        base.checked_add(offset as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    struct StaticCache;
+
+    #[async_trait::async_trait]
+    impl crate::TokenCache for StaticCache {
+        async fn token_and_exp(&self) -> Option<(String, u64)> {
+            Some(("token".to_string(), u64::MAX))
+        }
+
+        async fn set_token(&self, _: String, _: u64) -> crate::Result<()> {
+            Ok(())
+        }
+
+        async fn scope(&self) -> String {
+            String::new()
+        }
+
+        async fn fetch_token(&self, _: &reqwest::Client) -> crate::Result<(String, u64)> {
+            Err(crate::Error::new("the static token does not expire"))
+        }
+    }
+
+    // Serves the requested range of `content` to every connection.
+    fn serve(content: &'static [u8]) -> Result<String, Box<dyn std::error::Error>> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/file", listener.local_addr()?);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8(request).unwrap().to_lowercase();
+                let start: usize = request
+                    .split("range: bytes=")
+                    .nth(1)
+                    .and_then(|range| range.split('-').next())
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let body = &content[start..];
+                let head = format!(
+                    "HTTP/1.1 206 Partial Content\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                // the client may hang up early after a seek
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(body);
+            }
+        });
+        Ok(url)
+    }
+
+    #[tokio::test]
+    async fn seek_and_read() -> Result<(), Box<dyn std::error::Error>> {
+        let content = b"0123456789abcdefghij";
+        let url = serve(content)?;
+        let client = crate::Client::with_cache(StaticCache);
+        let mut reader = RangeReader::new(&client, url, content.len() as u64);
+
+        let mut buf = [0; 4];
+        reader.seek(SeekFrom::Start(10)).await?;
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"abcd");
+
+        reader.seek(SeekFrom::End(-3)).await?;
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await?;
+        assert_eq!(rest, b"hij");
+
+        reader.seek(SeekFrom::Start(2)).await?;
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"2345");
+        assert_eq!(reader.seek(SeekFrom::Current(1)).await?, 7);
+
+        assert!(reader.seek(SeekFrom::Current(-8)).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use futures_util::TryStream;
+
+use crate::{
+    bucket::StorageClass,
+    object::{CustomerEncryptionKey, PredefinedAcl},
+    object_access_control::NewObjectAccessControl,
+    Object, UploadOptions,
+};
+
+/// Configures the upload of a single object, which is started by one of the methods that provide
+/// its content: [`bytes`](UploadBuilder::bytes) or [`stream`](UploadBuilder::stream). All
+/// settings are sent along with the content in a single request.
+///
+/// An `UploadBuilder` is obtained using [`ObjectClient::upload`](super::ObjectClient::upload).
+/// ### Example
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use cloud_storage::Client;
+/// use cloud_storage::bucket::StorageClass;
+/// use cloud_storage::object::PredefinedAcl;
+///
+/// let client = Client::default();
+/// let object = client
+///     .object()
+///     .upload("my_bucket", "reports/2024.pdf")
+///     .content_type("application/pdf")
+///     .cache_control("private, max-age=0")
+///     .content_disposition("attachment; filename=\"report.pdf\"")
+///     .metadata("department", "finance")
+///     .storage_class(StorageClass::Nearline)
+///     .predefined_acl(PredefinedAcl::Private)
+///     .bytes(std::fs::read("report.pdf")?)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct UploadBuilder<'a> {
+    client: super::ObjectClient<'a>,
+    bucket: String,
+    name: String,
+    mime_type: String,
+    metadata: serde_json::Map<String, serde_json::Value>,
+    custom_metadata: serde_json::Map<String, serde_json::Value>,
+    predefined_acl: Option<PredefinedAcl>,
+    acl: Option<Vec<NewObjectAccessControl>>,
+    options: UploadOptions,
+}
+
+impl<'a> UploadBuilder<'a> {
+    pub(super) fn new(client: super::ObjectClient<'a>, bucket: &str, name: &str) -> Self {
+        Self {
+            client,
+            bucket: bucket.to_string(),
+            name: name.to_string(),
+            mime_type: "application/octet-stream".to_string(),
+            metadata: serde_json::Map::new(),
+            custom_metadata: serde_json::Map::new(),
+            predefined_acl: None,
+            acl: None,
+            options: UploadOptions::default(),
+        }
+    }
+
+    /// Sets the mime type of the content. Defaults to `application/octet-stream`.
+    pub fn content_type(mut self, mime_type: &str) -> Self {
+        self.mime_type = mime_type.to_string();
+        self
+    }
+
+    /// Sets the `Cache-Control` header that Google serves along with the object.
+    pub fn cache_control(self, cache_control: &str) -> Self {
+        self.field("cacheControl", cache_control)
+    }
+
+    /// Sets the `Content-Disposition` header that Google serves along with the object.
+    pub fn content_disposition(self, content_disposition: &str) -> Self {
+        self.field("contentDisposition", content_disposition)
+    }
+
+    /// Sets the `Content-Language` header that Google serves along with the object.
+    pub fn content_language(self, content_language: &str) -> Self {
+        self.field("contentLanguage", content_language)
+    }
+
+    /// Adds a custom metadata entry to the object. Entries set here take precedence over the
+    /// default metadata of the client.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_metadata
+            .insert(key.into(), serde_json::Value::String(value.into()));
+        self
+    }
+
+    /// Sets the storage class of the object. Defaults to the default storage class of the bucket.
+    pub fn storage_class(mut self, storage_class: StorageClass) -> Self {
+        // a unit variant always serializes to a string
+        let storage_class = serde_json::to_value(storage_class).unwrap();
+        self.metadata
+            .insert("storageClass".to_string(), storage_class);
+        self
+    }
+
+    /// Applies a predefined set of access controls to the object.
+    pub fn predefined_acl(mut self, predefined_acl: PredefinedAcl) -> Self {
+        self.predefined_acl = Some(predefined_acl);
+        self
+    }
+
+    /// Sets the access controls of the object to exactly `acl`, for grants that no predefined acl
+    /// covers. The object is created with these access controls, so there is no moment at which it
+    /// exists with the default ones. This is not possible in buckets with uniform bucket-level
+    /// access.
+    pub fn acl(mut self, acl: Vec<NewObjectAccessControl>) -> Self {
+        self.acl = Some(acl);
+        self
+    }
+
+    /// Encrypts the object with the Cloud KMS key with the resource name `kms_key_name`, in the
+    /// form `projects/{project}/locations/{location}/keyRings/{ring}/cryptoKeys/{key}`, instead of
+    /// the default key of the bucket.
+    pub fn kms_key_name(mut self, kms_key_name: &str) -> Self {
+        self.options = self.options.kms_key_name(kms_key_name);
+        self
+    }
+
+    /// Encrypts the object with a customer-supplied encryption key. The same key must be passed
+    /// to read the metadata, download, copy or rewrite the object afterwards, for example through
+    /// [`ReadParameters::encryption_key`](crate::object::ReadParameters::encryption_key).
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::object::CustomerEncryptionKey;
+    ///
+    /// let client = Client::default();
+    /// let key = CustomerEncryptionKey::from_base64(&std::env::var("ENCRYPTION_KEY")?)?;
+    /// let object = client
+    ///     .object()
+    ///     .upload("my_bucket", "backups/database.tar")
+    ///     .encryption_key(key)
+    ///     .bytes(std::fs::read("database.tar")?)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encryption_key(mut self, encryption_key: CustomerEncryptionKey) -> Self {
+        self.options = self.options.encryption_key(encryption_key);
+        self
+    }
+
+    /// Uploads `content` as the content of the object, and returns the created object.
+    pub async fn bytes(self, content: impl Into<Vec<u8>>) -> crate::Result<Object> {
+        let content = content.into();
+        let length = content.len() as u64;
+        let stream =
+            futures_util::stream::once(async { Ok::<_, std::convert::Infallible>(content) });
+        self.stream(stream, length).await
+    }
+
+    /// Uploads the content of the object from `stream`, and returns the created object. When the
+    /// upload is incomplete, it is handled as described for `ObjectClient::create_streamed`.
+    pub async fn stream<S>(self, stream: S, length: impl Into<Option<u64>>) -> crate::Result<Object>
+    where
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        bytes::Bytes: From<S::Ok>,
+    {
+        let metadata = self.resource()?;
+        self.client
+            .upload_multipart(
+                &self.bucket,
+                stream,
+                length.into(),
+                &self.name,
+                &self.mime_type,
+                &metadata,
+                self.predefined_acl,
+                &self.options,
+            )
+            .await
+    }
+
+    // The (partial) object resource that is sent along with the content.
+    fn resource(&self) -> crate::Result<serde_json::Value> {
+        let mut resource = self.metadata.clone();
+        if !self.custom_metadata.is_empty() {
+            let custom_metadata = serde_json::Value::Object(self.custom_metadata.clone());
+            resource.insert("metadata".to_string(), custom_metadata);
+        }
+        if let Some(ref acl) = self.acl {
+            resource.insert("acl".to_string(), serde_json::to_value(acl)?);
+        }
+        Ok(serde_json::Value::Object(resource))
+    }
+
+    fn field(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Entity, Role};
+
+    #[test]
+    fn resource_contains_settings() -> Result<(), Box<dyn std::error::Error>> {
+        let client = crate::Client::new();
+        let acl = vec![NewObjectAccessControl {
+            entity: Entity::UserEmail("liz@example.com".to_string()),
+            role: Role::Reader,
+        }];
+        let builder = client
+            .object()
+            .upload("my_bucket", "report.pdf")
+            .cache_control("no-cache")
+            .metadata("department", "finance")
+            .storage_class(StorageClass::Nearline)
+            .acl(acl);
+        assert_eq!(
+            builder.resource()?,
+            serde_json::json!({
+                "cacheControl": "no-cache",
+                "storageClass": "NEARLINE",
+                "metadata": { "department": "finance" },
+                "acl": [{ "entity": "user-liz@example.com", "role": "READER" }],
+            })
+        );
+        Ok(())
+    }
+}
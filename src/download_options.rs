@@ -1,7 +1,15 @@
+use crate::resources::service_account::ServiceAccount;
+use std::time::Duration;
+
 /// A set of parameters that can be used to customise signed urls.
 #[derive(Default)]
 pub struct DownloadOptions {
     pub(crate) content_disposition: Option<String>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) cache_control: Option<String>,
+    pub(crate) content_encoding: Option<String>,
+    pub(crate) service_account: Option<ServiceAccount>,
+    pub(crate) clock_skew: Duration,
 }
 
 impl DownloadOptions {
@@ -30,4 +38,101 @@ impl DownloadOptions {
         self.content_disposition = Some(content_disposition.to_string());
         self
     }
+
+    /// Overrides the `Content-Type` header of the response, for example to let a browser display
+    /// an object that was stored as `application/octet-stream`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::DownloadOptions;
+    ///
+    /// let opts = DownloadOptions::new()
+    ///     .content_type("image/png");
+    /// ```
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Overrides the `Cache-Control` header of the response.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::DownloadOptions;
+    ///
+    /// let opts = DownloadOptions::new()
+    ///     .cache_control("private, max-age=300");
+    /// ```
+    pub fn cache_control(mut self, cache_control: &str) -> Self {
+        self.cache_control = Some(cache_control.to_string());
+        self
+    }
+
+    /// Overrides the `Content-Encoding` header of the response.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::DownloadOptions;
+    ///
+    /// let opts = DownloadOptions::new()
+    ///     .content_encoding("gzip");
+    /// ```
+    pub fn content_encoding(mut self, content_encoding: &str) -> Self {
+        self.content_encoding = Some(content_encoding.to_string());
+        self
+    }
+
+    /// Sign the url with the provided service account, instead of the one that is configured
+    /// through the environment. This allows urls to be signed by a dedicated identity, separate
+    /// from the one that performs the other operations.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{service_account::ServiceAccount, DownloadOptions};
+    ///
+    /// let json = std::fs::read_to_string("url-signer.json")?;
+    /// let opts = DownloadOptions::new()
+    ///     .service_account(ServiceAccount::from_json(&json)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_account(mut self, service_account: ServiceAccount) -> Self {
+        self.service_account = Some(service_account);
+        self
+    }
+
+    /// Dates the url `clock_skew` in the past, to allow for a clock on this machine that runs ahead
+    /// of Google's. Without it, a url signed on such a machine is rejected as not yet valid until
+    /// the difference has passed. The url still expires `duration` seconds from now, so the
+    /// allowance is added to its lifetime, which may not exceed 7 days in total. A few minutes is
+    /// usually enough. Defaults to no allowance.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::DownloadOptions;
+    /// use std::time::Duration;
+    ///
+    /// let opts = DownloadOptions::new()
+    ///     .clock_skew(Duration::from_secs(300));
+    /// ```
+    pub fn clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    // The query parameters that override the headers of the response, sorted by name as the
+    // canonical query string of a signed url requires.
+    pub(crate) fn response_parameters(&self) -> Vec<(&'static str, &str)> {
+        let parameters = [
+            ("response-cache-control", &self.cache_control),
+            ("response-content-disposition", &self.content_disposition),
+            ("response-content-encoding", &self.content_encoding),
+            ("response-content-type", &self.content_type),
+        ];
+        parameters
+            .iter()
+            .filter_map(|(name, value)| Some((*name, value.as_deref()?)))
+            .collect()
+    }
 }
@@ -3,6 +3,10 @@
 pub enum Error {
     /// If the error is caused by a non 2xx response by Google, this variant is returned.
     Google(GoogleErrorResponse),
+    /// If Google refused an operation because one of its preconditions did not hold, this variant
+    /// is returned instead of `Google`. This means that the resource was changed by someone else
+    /// since the precondition was determined, so it should be read again before retrying.
+    PreconditionFailed(GoogleErrorResponse),
     /// If another network error causes something to fail, this variant is used.
     Reqwest(reqwest::Error),
     /// If we encounter a problem decoding the private key, this variant is used.
@@ -21,6 +25,22 @@ pub enum Error {
     Jwt(jsonwebtoken::errors::Error),
     /// If we cannot deserialize one of the repsonses sent by Google, this variant is used.
     Serialization(serde_json::error::Error),
+    /// If no valid token could be obtained to authenticate with Google, this variant is used. It
+    /// contains a description of the underlying cause, such as an invalid private key or a
+    /// rejected token request.
+    Auth(String),
+    /// If an operation did not complete within the time that was allotted to it, this variant is
+    /// used. When a request exceeded the timeouts configured on the `ClientBuilder`, it contains
+    /// the error of that request. It is `None` when an operation ran out of the time that was
+    /// passed to it, such as the `timeout` of `ObjectClient::wait_for`.
+    Timeout(Option<reqwest::Error>),
+    /// If an object name is refused by the `ObjectNamePolicy` of the client, this variant is used.
+    /// It contains the refused name.
+    InvalidObjectName(String),
+    /// If a change to the retention configuration of an object was rejected because the
+    /// configuration is locked, and the change would shorten or remove it, this variant is used.
+    /// It contains the time until which the object is retained.
+    RetentionLocked(chrono::DateTime<chrono::Utc>),
     /// If another failure causes the error, this variant is populated.
     Other(String),
 }
@@ -29,6 +49,12 @@ impl Error {
     pub(crate) fn new(msg: &str) -> Error {
         Error::Other(msg.to_string())
     }
+
+    /// Returns whether Google refused the operation because one of its preconditions did not hold,
+    /// see `Error::PreconditionFailed`.
+    pub fn is_precondition_failed(&self) -> bool {
+        matches!(self, Error::PreconditionFailed(_))
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -41,6 +67,7 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Google(e) => Some(e),
+            Self::PreconditionFailed(e) => Some(e),
             Self::Reqwest(e) => Some(e),
             #[cfg(feature = "openssl")]
             Self::Ssl(e) => Some(e),
@@ -52,6 +79,10 @@ impl std::error::Error for Error {
             Self::Signing(e) => Some(e),
             Self::Jwt(e) => Some(e),
             Self::Serialization(e) => Some(e),
+            Self::Auth(_) => None,
+            Self::Timeout(e) => e.as_ref().map(|e| e as _),
+            Self::InvalidObjectName(_) => None,
+            Self::RetentionLocked(_) => None,
             Self::Other(_) => None,
         }
     }
@@ -59,7 +90,11 @@ impl std::error::Error for Error {
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        Self::Reqwest(err)
+        if err.is_timeout() {
+            Self::Timeout(Some(err))
+        } else {
+            Self::Reqwest(err)
+        }
     }
 }
 
@@ -109,6 +144,12 @@ impl From<reqwest::header::InvalidHeaderValue> for Error {
     }
 }
 
+impl From<reqwest::header::ToStrError> for Error {
+    fn from(err: reqwest::header::ToStrError) -> Self {
+        Self::Other(err.to_string())
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
         Self::Other(err.to_string())
@@ -233,7 +274,11 @@ impl GoogleError {
 
 impl From<GoogleErrorResponse> for Error {
     fn from(err: GoogleErrorResponse) -> Self {
-        Self::Google(err)
+        if err.error.code == 412 || err.errors_has_reason(&Reason::ConditionNotMet) {
+            Self::PreconditionFailed(err)
+        } else {
+            Self::Google(err)
+        }
     }
 }
 
@@ -459,22 +504,43 @@ pub enum Reason {
     GatewayTimeout,
 }
 
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename = "camelCase")]
-enum BadRequest {}
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename = "camelCase")]
-enum Unauthorized {}
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename = "camelCase")]
-enum Forbidden {}
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename = "camelCase")]
-enum PreconditionFailed {}
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename = "camelCase")]
-enum InternalServerError {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precondition_failed() -> Result<(), Box<dyn std::error::Error>> {
+        let response = r#"{
+            "error": {
+                "code": 412,
+                "message": "At least one of the pre-conditions you specified did not hold.",
+                "errors": [{
+                    "message": "At least one of the pre-conditions you specified did not hold.",
+                    "domain": "global",
+                    "reason": "conditionNotMet",
+                    "locationType": "header",
+                    "location": "If-Match"
+                }]
+            }
+        }"#;
+        let error: GoogleErrorResponse = serde_json::from_str(response)?;
+        let error = Error::from(error);
+        assert!(matches!(error, Error::PreconditionFailed(_)));
+        assert!(error.is_precondition_failed());
+
+        let response = r#"{
+            "error": {
+                "code": 404,
+                "message": "No such object: my-bucket/file.txt",
+                "errors": [{
+                    "message": "No such object: my-bucket/file.txt",
+                    "domain": "global",
+                    "reason": "notFound"
+                }]
+            }
+        }"#;
+        let error: GoogleErrorResponse = serde_json::from_str(response)?;
+        assert!(matches!(Error::from(error), Error::Google(_)));
+        Ok(())
+    }
+}
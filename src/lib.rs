@@ -23,6 +23,10 @@
 //! The two most important concepts are [Buckets](bucket/struct.Bucket.html), which represent
 //! file systems, and [Objects](object/struct.Object.html), which represent files.
 //!
+//! All timestamps returned by Google, such as `Object::time_created` and `Bucket::updated`, are
+//! represented as `chrono::DateTime<chrono::Utc>`. This is the only representation this crate
+//! uses, so no additional feature is needed to work with them.
+//!
 //! ## Examples:
 //! Creating a new Bucket in Google Cloud Storage:
 //! ```rust
@@ -91,12 +95,15 @@
 pub mod client;
 #[cfg(feature = "sync")]
 pub mod sync;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 mod download_options;
 mod error;
 /// Contains objects as represented by Google, to be used for serialization and deserialization.
 mod resources;
 mod token;
+mod upload_options;
 
 use crate::resources::service_account::ServiceAccount;
 pub use crate::{
@@ -107,10 +114,11 @@ pub use crate::{
         object::{ListRequest, Object},
         *,
     },
-    token::{Token, TokenCache},
+    token::{AnonymousToken, Scope, Token, TokenCache},
 };
 pub use download_options::DownloadOptions;
 use tokio::sync::Mutex;
+pub use upload_options::UploadOptions;
 
 lazy_static::lazy_static! {
     static ref IAM_TOKEN_CACHE: Mutex<Token> = Mutex::new(Token::new(
@@ -121,6 +129,11 @@ lazy_static::lazy_static! {
     /// debugging of which service account is currently used. It is of the type
     /// [ServiceAccount](service_account/struct.ServiceAccount.html).
     pub static ref SERVICE_ACCOUNT: ServiceAccount = ServiceAccount::get();
+
+    // The service account that is configured through the environment, or the reason why there is
+    // none, for the places that must not panic when it is missing.
+    static ref ENV_SERVICE_ACCOUNT: std::result::Result<ServiceAccount, String> =
+        ServiceAccount::from_env();
 }
 
 #[cfg(feature = "global-client")]
@@ -164,6 +177,57 @@ where
     }
 }
 
+// The resource that Google returns for an object with the specified name in the specified bucket,
+// with "hello world" as its content.
+#[cfg(test)]
+fn test_object_response(bucket: &str, name: &str) -> serde_json::Value {
+    use crate::resources::object::percent_encode;
+
+    serde_json::json!({
+        "kind": "storage#object",
+        "id": format!("{}/{}/1617000000000000", bucket, name),
+        "selfLink": format!(
+            "https://www.googleapis.com/storage/v1/b/{}/o/{}",
+            bucket,
+            percent_encode(name)
+        ),
+        "mediaLink": format!(
+            "https://storage.googleapis.com/download/storage/v1/b/{}/o/{}?generation=1617000000000000&alt=media",
+            bucket,
+            percent_encode(name)
+        ),
+        "name": name,
+        "bucket": bucket,
+        "generation": "1617000000000000",
+        "metageneration": "1",
+        "storageClass": "STANDARD",
+        "size": "11",
+        "md5Hash": "XrY7u+Ae7tCTyyK7j1rNww==",
+        "crc32c": "yZRlqg==",
+        "etag": "CIDk5qq8iu8CEAE=",
+        "timeCreated": "2021-03-29T06:40:00.000Z",
+        "updated": "2021-03-29T06:40:00.000Z",
+        "timeStorageClassUpdated": "2021-03-29T06:40:00.000Z"
+    })
+}
+
+// An object with the specified name in the specified bucket, see `test_object_response`.
+#[cfg(test)]
+fn test_object(bucket: &str, name: &str) -> Object {
+    serde_json::from_value(test_object_response(bucket, name)).unwrap()
+}
+
+// The query string that `parameters` add to the url of a request.
+#[cfg(test)]
+fn query_of(parameters: &impl serde::Serialize) -> Option<String> {
+    let request = reqwest::Client::new()
+        .get("https://example.com")
+        .query(parameters)
+        .build()
+        .unwrap();
+    request.url().query().map(String::from)
+}
+
 #[cfg(all(test, feature = "global-client", feature = "sync"))]
 fn read_test_bucket_sync() -> Bucket {
     crate::runtime().unwrap().block_on(read_test_bucket())
@@ -61,7 +61,9 @@ pub struct Bucket {
     /// authoritative list.
     pub location: Location,
     /// The type of location that the bucket resides in, as determined by the location property.
-    pub location_type: String,
+    /// Unlike `Location::location_type`, this is also known for locations that this crate does not
+    /// model explicitly.
+    pub location_type: Option<LocationType>,
     /// The bucket's website configuration, controlling how the service behaves when accessing
     /// bucket contents as a web site. See the Static Website Examples for more information.
     pub website: Option<Website>,
@@ -84,10 +86,68 @@ pub struct Bucket {
     pub storage_class: StorageClass,
     /// The bucket's billing configuration.
     pub billing: Option<Billing>,
+    /// The recovery point objective for replication of the bucket, which only applies to
+    /// dual-region buckets.
+    pub rpo: Option<Rpo>,
     /// HTTP 1.1 [Entity tag](https://tools.ietf.org/html/rfc7232#section-2.3) for the bucket.
     pub etag: String,
 }
 
+/// The query parameters that can be supplied when performing `Bucket::read`.
+/// See [the Google Cloud Storage API
+/// reference](https://cloud.google.com/storage/docs/json_api/v1/buckets/get)
+/// for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadParameters {
+    /// Set of properties to return. Defaults to `NoAcl`.
+    pub projection: Option<crate::object::Projection>,
+    /// Only return the bucket if its current metageneration matches the given value.
+    pub if_metageneration_match: Option<i64>,
+    /// Only return the bucket if its current metageneration does not match the given value.
+    pub if_metageneration_not_match: Option<i64>,
+    /// Additional query parameters, which are sent as they are. These can be used for parameters
+    /// that this crate does not support yet.
+    #[serde(flatten)]
+    pub extra_query: std::collections::BTreeMap<String, String>,
+}
+
+/// Conditions that must hold for an operation on a bucket to be performed. When they do not hold,
+/// Google responds with a `conditionNotMet` error, which is returned as
+/// `Error::PreconditionFailed`. Managed folders have a metageneration of their own, so the same
+/// conditions are used for them. See [the Google Cloud Storage
+/// documentation](https://cloud.google.com/storage/docs/request-preconditions) for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Preconditions {
+    /// Makes the operation conditional on whether the bucket's current metageneration matches the
+    /// given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_metageneration_match: Option<i64>,
+
+    /// Makes the operation conditional on whether the bucket's current metageneration does not
+    /// match the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_metageneration_not_match: Option<i64>,
+}
+
+/// The query parameters that can be supplied when performing `Bucket::update_with`.
+/// See [the Google Cloud Storage API
+/// reference](https://cloud.google.com/storage/docs/json_api/v1/buckets/update)
+/// for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParameters {
+    /// Replaces the default object ACL of the bucket with a predefined set of access controls, in
+    /// the same request that updates the bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_default_object_acl: Option<crate::object::PredefinedAcl>,
+
+    /// Conditions on the bucket that is updated.
+    #[serde(flatten)]
+    pub preconditions: Preconditions,
+}
+
 /// A model that can be used to insert new buckets into Google Cloud Storage.
 #[derive(Debug, PartialEq, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -135,6 +195,14 @@ pub struct NewBucket {
     pub storage_class: Option<StorageClass>,
     /// The bucket's billing configuration.
     pub billing: Option<Billing>,
+    /// The recovery point objective for replication of the bucket. This can only be set for
+    /// dual-region buckets.
+    pub rpo: Option<Rpo>,
+    /// A predefined set of default access controls to apply to new objects when no ACL is
+    /// provided, as a shorthand for `default_object_acl`. This is sent as the
+    /// `predefinedDefaultObjectAcl` query parameter rather than as part of the bucket.
+    #[serde(skip)]
+    pub predefined_default_object_acl: Option<crate::object::PredefinedAcl>,
 }
 
 /// Contains information about how files are kept after deletion.
@@ -190,7 +258,7 @@ pub struct Encryption {
 }
 
 /// Contains information about an entity that is able to own a `Bucket`.
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Owner {
     /// The entity, in the form project-owner-projectId.
@@ -206,10 +274,12 @@ pub struct Website {
     /// If the requested object path is missing, the service will ensure the path has a trailing
     /// '/', append this suffix, and attempt to retrieve the resulting object. This allows the
     /// creation of index.html objects to represent directory pages.
+    #[serde(default)]
     pub main_page_suffix: String,
     /// If the requested object path is missing, and any mainPageSuffix object is missing, if
     /// applicable, the service will return the named object from this bucket as the content for a
     /// 404 Not Found result.
+    #[serde(default)]
     pub not_found_page: String,
 }
 
@@ -220,6 +290,7 @@ pub struct Logging {
     /// The destination bucket where the current bucket's logs should be placed.
     pub log_bucket: String,
     /// A prefix for log object names. The default prefix is the bucket name.
+    #[serde(default)]
     pub log_object_prefix: String,
 }
 
@@ -261,6 +332,55 @@ pub struct Lifecycle {
     pub rule: Vec<Rule>,
 }
 
+impl Lifecycle {
+    /// A lifecycle that deletes objects once they are `days` days old.
+    /// ### Example
+    /// ```
+    /// use cloud_storage::bucket::{Lifecycle, StorageClass};
+    ///
+    /// // move objects to Coldline after a month, and delete them after a year
+    /// let lifecycle = Lifecycle::transition_to(StorageClass::Coldline, 30)
+    ///     .and(Lifecycle::delete_after_days(365));
+    /// assert_eq!(lifecycle.rule.len(), 2);
+    /// ```
+    pub fn delete_after_days(days: i32) -> Self {
+        Self::single(
+            Action {
+                r#type: ActionType::Delete,
+                storage_class: None,
+            },
+            days,
+        )
+    }
+
+    /// A lifecycle that moves objects to `storage_class` once they are `after_days` days old.
+    pub fn transition_to(storage_class: StorageClass, after_days: i32) -> Self {
+        Self::single(
+            Action {
+                r#type: ActionType::SetStorageClass,
+                storage_class: Some(storage_class),
+            },
+            after_days,
+        )
+    }
+
+    /// Combines the rules of this lifecycle with the rules of `other`.
+    pub fn and(mut self, other: Lifecycle) -> Self {
+        self.rule.extend(other.rule);
+        self
+    }
+
+    fn single(action: Action, age: i32) -> Self {
+        let condition = Condition {
+            age: Some(age),
+            ..Default::default()
+        };
+        Self {
+            rule: vec![Rule { action, condition }],
+        }
+    }
+}
+
 /// An element of the lifecyle list.
 #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -288,10 +408,12 @@ pub enum ActionType {
     Delete,
     /// Sets the `storage_class` of a Bucket.
     SetStorageClass,
+    /// Aborts incomplete multipart uploads that were started using the XML API.
+    AbortIncompleteMultipartUpload,
 }
 
 /// A rule that might induce an `Action` if met.
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Condition {
     /// Age of an object (in days). This condition is satisfied when an object reaches the specified
@@ -313,6 +435,13 @@ pub struct Condition {
     /// object.
     #[serde(default, deserialize_with = "crate::from_str_opt")]
     pub num_newer_versions: Option<i32>,
+    /// Number of days elapsed since the user-specified timestamp set on an object. This condition
+    /// is satisfied when the object's `custom_time` lies at least this many days in the past.
+    pub days_since_custom_time: Option<i32>,
+    /// A date in `RFC 3339` format with only the date part (for instance, "2013-01-15"). This
+    /// condition is satisfied when the object's `custom_time` lies before midnight of the
+    /// specified date in UTC.
+    pub custom_time_before: Option<chrono::NaiveDate>,
 }
 
 /// Contains information about the payment structure of this bucket
@@ -350,6 +479,22 @@ pub enum StorageClass {
     ///
     /// You can move your data from DRA to other storage classes by performing a storage transfer.
     DurableReducedAvailability,
+    /// Archive Storage is the lowest-cost, highly durable storage service for data archiving,
+    /// online backup, and disaster recovery.
+    Archive,
+}
+
+/// The [recovery point objective](https://cloud.google.com/storage/docs/availability-durability#turbo-replication)
+/// of a dual-region bucket, which determines how fast newly written objects are replicated to
+/// the second region.
+#[derive(Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Rpo {
+    /// Default replication, which replicates most new objects within an hour, and all of them
+    /// within twelve hours.
+    Default,
+    /// Turbo replication, which replicates all new objects within fifteen minutes.
+    AsyncTurbo,
 }
 
 /// A representation of the IAM Policiy for a certain bucket.
@@ -535,7 +680,8 @@ pub struct TestIamPermission {
     /// [Cloud Storage IAM Permissions]
     /// (https://cloud.google.com/storage/docs/access-control/iam-permissions) for a list of
     /// supported permissions.
-    permissions: Vec<String>,
+    #[serde(default)]
+    pub(crate) permissions: Vec<String>,
 }
 
 impl Bucket {
@@ -815,11 +961,134 @@ impl Bucket {
         crate::runtime()?.block_on(self.test_iam_permission(permission))
     }
 
+    /// Checks which of `permissions` the user provided in the service account has, in a single
+    /// request, and returns the ones it has.
+    /// ### Example
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Bucket;
+    ///
+    /// let bucket = Bucket::read("my-bucket").await?;
+    /// let granted = bucket
+    ///     .test_iam_permissions(&["storage.objects.get", "storage.objects.list"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "global-client")]
+    pub async fn test_iam_permissions(&self, permissions: &[&str]) -> crate::Result<Vec<String>> {
+        crate::CLOUD_CLIENT
+            .bucket()
+            .test_iam_permissions(self, permissions)
+            .await
+    }
+
+    /// The synchronous equivalent of `Bucket::test_iam_permissions`.
+    ///
+    /// ### Features
+    /// This function requires that the feature flag `sync` is enabled in `Cargo.toml`.
+    #[cfg(all(feature = "global-client", feature = "sync"))]
+    pub fn test_iam_permissions_sync(&self, permissions: &[&str]) -> crate::Result<Vec<String>> {
+        crate::runtime()?.block_on(self.test_iam_permissions(permissions))
+    }
+
     fn _lock_retention_policy() {
         todo!()
     }
 }
 
+#[cfg(test)]
+mod deserialize_tests {
+    use super::*;
+
+    #[test]
+    fn bucket_with_newer_fields() -> Result<(), Box<dyn std::error::Error>> {
+        // a response as sent by Google, containing fields and values that this crate does not
+        // model explicitly.
+        let response = r#"{
+            "kind": "storage#bucket",
+            "selfLink": "https://www.googleapis.com/storage/v1/b/my-bucket",
+            "id": "my-bucket",
+            "name": "my-bucket",
+            "projectNumber": "123456789012",
+            "metageneration": "3",
+            "location": "US-SOUTH1",
+            "locationType": "region",
+            "storageClass": "ARCHIVE",
+            "etag": "CAM=",
+            "timeCreated": "2021-03-01T10:00:00.000Z",
+            "updated": "2021-03-02T10:00:00.000Z",
+            "website": { "mainPageSuffix": "index.html" },
+            "lifecycle": {
+                "rule": [{
+                    "action": { "type": "AbortIncompleteMultipartUpload" },
+                    "condition": { "age": 7 }
+                }]
+            },
+            "iamConfiguration": {
+                "bucketPolicyOnly": { "enabled": false },
+                "uniformBucketLevelAccess": { "enabled": false },
+                "publicAccessPrevention": "inherited"
+            },
+            "rpo": "DEFAULT",
+            "softDeletePolicy": {
+                "retentionDurationSeconds": "604800",
+                "effectiveTime": "2024-03-01T08:00:00.000Z"
+            },
+            "hierarchicalNamespace": { "enabled": false }
+        }"#;
+        let bucket: Bucket = serde_json::from_str(response)?;
+        assert_eq!(bucket.location, Location::Other("US-SOUTH1".to_string()));
+        assert_eq!(bucket.location.location_type(), None);
+        assert_eq!(bucket.location_type, Some(LocationType::Region));
+        assert_eq!(bucket.storage_class, StorageClass::Archive);
+        assert_eq!(bucket.rpo, Some(Rpo::Default));
+        assert_eq!(bucket.website.unwrap().not_found_page, "");
+        Ok(())
+    }
+
+    #[test]
+    fn location_types() -> Result<(), Box<dyn std::error::Error>> {
+        let location_type: LocationType = serde_json::from_str(r#""dual-region""#)?;
+        assert_eq!(location_type, LocationType::DualRegion);
+        assert_eq!(
+            Location::Dual(DualRegion::Nam4).location_type(),
+            Some(location_type)
+        );
+        assert_eq!(
+            Location::Multi(MultiRegion::Eu).location_type(),
+            Some(LocationType::MultiRegion)
+        );
+        let location_type: LocationType = serde_json::from_str(r#""tri-region""#)?;
+        assert_eq!(location_type, LocationType::Other("tri-region".to_string()));
+        assert_eq!(serde_json::to_string(&location_type)?, r#""tri-region""#);
+        Ok(())
+    }
+
+    #[test]
+    fn no_permissions_held() -> Result<(), Box<dyn std::error::Error>> {
+        // Google omits the permissions when none of them are held
+        let response = r#"{ "kind": "storage#testIamPermissionsResponse" }"#;
+        let held: TestIamPermission = serde_json::from_str(response)?;
+        assert!(held.permissions.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn lifecycle_helpers() -> Result<(), Box<dyn std::error::Error>> {
+        let lifecycle = Lifecycle::transition_to(StorageClass::Coldline, 30)
+            .and(Lifecycle::delete_after_days(365));
+        let json = serde_json::to_value(&lifecycle)?;
+        assert_eq!(json["rule"][0]["action"]["type"], "SetStorageClass");
+        assert_eq!(json["rule"][0]["action"]["storageClass"], "COLDLINE");
+        assert_eq!(json["rule"][0]["condition"]["age"], 30);
+        assert_eq!(json["rule"][1]["action"]["type"], "Delete");
+        assert_eq!(json["rule"][1]["condition"]["age"], 365);
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "global-client"))]
 mod tests {
     use super::*;
@@ -875,6 +1144,95 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn update_with_metageneration() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::read_test_bucket().await;
+        let client = crate::CLOUD_CLIENT.bucket();
+        let current = UpdateParameters {
+            preconditions: Preconditions {
+                if_metageneration_match: Some(bucket.metageneration),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let updated = client.update_with(&bucket, &current).await?;
+        assert!(updated.metageneration > bucket.metageneration);
+        // the bucket changed since `bucket` was read
+        let result = client.update_with(&bucket, &current).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::PreconditionFailed(ref e)) if e.errors_has_reason(&crate::error::Reason::ConditionNotMet)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_with_metageneration() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::read_test_bucket().await;
+        let client = crate::CLOUD_CLIENT.bucket();
+        let unchanged = ReadParameters {
+            if_metageneration_not_match: Some(bucket.metageneration),
+            ..Default::default()
+        };
+        assert_eq!(client.read_with(&bucket.name, &unchanged).await?, None);
+        let current = ReadParameters {
+            if_metageneration_match: Some(bucket.metageneration),
+            ..Default::default()
+        };
+        assert!(client.read_with(&bucket.name, &current).await?.is_some());
+        let changed = ReadParameters {
+            if_metageneration_match: Some(bucket.metageneration + 1),
+            ..Default::default()
+        };
+        let result = client.read_with(&bucket.name, &changed).await;
+        assert!(matches!(result, Err(crate::Error::PreconditionFailed(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exists() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::read_test_bucket().await;
+        let client = crate::CLOUD_CLIENT.bucket();
+        assert!(client.exists(&bucket.name).await?);
+        assert!(
+            !client
+                .exists("cloud-storage-rs-test-bucket-that-does-not-exist")
+                .await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_and_clear_website() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::create_test_bucket("test-set-website").await;
+        let website = Website {
+            main_page_suffix: "index.html".to_string(),
+            not_found_page: "404.html".to_string(),
+        };
+        let client = crate::CLOUD_CLIENT.bucket();
+        let updated = client.set_website(&bucket.name, &website).await?;
+        assert_eq!(updated.website, Some(website));
+        let cleared = client.clear_website(&bucket.name).await?;
+        assert_eq!(cleared.website, None);
+        bucket.delete().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_lifecycle() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::create_test_bucket("test-set-lifecycle").await;
+        let lifecycle = Lifecycle::delete_after_days(30);
+        let updated = crate::CLOUD_CLIENT
+            .bucket()
+            .set_lifecycle(&bucket.name, &lifecycle)
+            .await?;
+        let rule = &updated.lifecycle.unwrap().rule[0];
+        assert_eq!(rule.action.r#type, ActionType::Delete);
+        assert_eq!(rule.condition.age, Some(30));
+        bucket.delete().await?;
+        Ok(())
+    }
+
     // used a lot throughout the other tests, but included for completeness
     #[tokio::test]
     async fn delete() -> Result<(), Box<dyn std::error::Error>> {
@@ -916,6 +1274,21 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_iam_permissions() -> Result<(), Box<dyn std::error::Error>> {
+        let bucket = crate::read_test_bucket().await;
+        let granted = bucket
+            .test_iam_permissions(&["storage.buckets.get", "storage.objects.list"])
+            .await?;
+        assert!(granted.iter().any(|p| p == "storage.buckets.get"));
+        assert!(granted.iter().any(|p| p == "storage.objects.list"));
+        assert!(bucket
+            .test_iam_permissions(&["storage.objects.get", "storage.buckets.create"])
+            .await
+            .is_err());
+        Ok(())
+    }
+
     #[cfg(all(feature = "global-client", feature = "sync"))]
     mod sync {
         use super::*;
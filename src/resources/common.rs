@@ -2,7 +2,7 @@ use serde::Serializer;
 use std::str::FromStr;
 
 /// Contains information about the team related to this `DefaultObjectAccessControls`
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectTeam {
     /// The project number.
@@ -47,7 +47,7 @@ impl FromStr for Team {
 }
 
 /// Any type of role we can encounter.
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Role {
     /// Full access.
@@ -90,6 +90,70 @@ pub enum Entity {
 
 use Entity::*;
 
+impl Entity {
+    /// A single user, identified by either its email address or its id. Email addresses are
+    /// recognised by the `@` they contain, in the same way as when entities are read from Google.
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::bucket::Entity;
+    ///
+    /// let entity = Entity::user("alice@example.com");
+    /// assert_eq!(entity, Entity::UserEmail("alice@example.com".to_string()));
+    /// assert_eq!(entity.to_string(), "user-alice@example.com");
+    /// ```
+    pub fn user(id_or_email: impl Into<String>) -> Self {
+        let id_or_email = id_or_email.into();
+        if id_or_email.contains('@') {
+            UserEmail(id_or_email)
+        } else {
+            UserId(id_or_email)
+        }
+    }
+
+    /// A group of users, identified by either its email address or its id, which are told apart
+    /// in the same way as for `Entity::user`.
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::bucket::Entity;
+    ///
+    /// let entity = Entity::group("admins@example.com");
+    /// assert_eq!(entity.to_string(), "group-admins@example.com");
+    /// ```
+    pub fn group(id_or_email: impl Into<String>) -> Self {
+        let id_or_email = id_or_email.into();
+        if id_or_email.contains('@') {
+            GroupEmail(id_or_email)
+        } else {
+            GroupId(id_or_email)
+        }
+    }
+
+    /// All users with an email address in `domain`, which is given without the `@`.
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::bucket::Entity;
+    ///
+    /// let entity = Entity::domain("example.com");
+    /// assert_eq!(entity.to_string(), "domain-example.com");
+    /// ```
+    pub fn domain(domain: impl Into<String>) -> Self {
+        Domain(domain.into())
+    }
+
+    /// All users that are part of `team` within the project with id `project_id`.
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::bucket::Entity;
+    /// use cloud_storage::common::Team;
+    ///
+    /// let entity = Entity::project_team(Team::Viewers, "my-project");
+    /// assert_eq!(entity.to_string(), "project-viewers-my-project");
+    /// ```
+    pub fn project_team(team: Team, project_id: impl Into<String>) -> Self {
+        Project(team, project_id.into())
+    }
+}
+
 impl std::fmt::Display for Entity {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -207,6 +271,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn constructors() {
+        assert_eq!(Entity::user("12345"), UserId("12345".to_string()));
+        assert_eq!(
+            Entity::user("alice@example.com"),
+            UserEmail("alice@example.com".to_string())
+        );
+        assert_eq!(Entity::group("67890"), GroupId("67890".to_string()));
+        assert_eq!(
+            Entity::group("admins@example.com"),
+            GroupEmail("admins@example.com".to_string())
+        );
+        assert_eq!(
+            Entity::domain("example.com"),
+            Domain("example.com".to_string())
+        );
+        assert_eq!(
+            Entity::project_team(Team::Owners, "my-project"),
+            Project(Team::Owners, "my-project".to_string())
+        );
+    }
+
     #[test]
     fn deserialize() {
         let str1 = "\"user-some id\"";
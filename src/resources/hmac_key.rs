@@ -46,6 +46,15 @@ pub struct HmacMeta {
     pub etag: String,
 }
 
+/// A rotation of an HMAC key that was started with `HmacKeyClient::rotate`.
+#[derive(Debug, PartialEq)]
+pub struct HmacRotation {
+    /// The new key, which is active. This is the only time its secret is available.
+    pub key: HmacKey,
+    /// The key that is being replaced, which is still active.
+    pub previous: HmacMeta,
+}
+
 /// The state of an Hmac Key.
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -58,6 +67,19 @@ pub enum HmacState {
     Deleted,
 }
 
+/// The query parameters that can be supplied when listing HMAC keys.
+/// See [the Google Cloud Storage API
+/// reference](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/list)
+/// for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HmacListRequest {
+    /// If present, only keys for the given service account are returned.
+    pub service_account_email: Option<String>,
+    /// Whether or not to show keys in the `Deleted` state. Defaults to `false`.
+    pub show_deleted_keys: Option<bool>,
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub(crate) struct ListResponse {
     pub(crate) items: Vec<HmacMeta>,
@@ -238,6 +260,36 @@ impl HmacKey {
     }
 }
 
+#[cfg(test)]
+mod deserialize_tests {
+    use super::*;
+
+    #[test]
+    fn deleted_state() -> Result<(), Box<dyn std::error::Error>> {
+        let state: HmacState = serde_json::from_str(r#""DELETED""#)?;
+        assert_eq!(state, HmacState::Deleted);
+        assert_eq!(serde_json::to_string(&state)?, r#""DELETED""#);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod request_tests {
+    use super::*;
+
+    #[test]
+    fn list_request_query() {
+        let list_request = HmacListRequest {
+            service_account_email: Some("auditor@my-project.iam.gserviceaccount.com".to_string()),
+            show_deleted_keys: Some(true),
+        };
+        assert_eq!(
+            crate::query_of(&list_request).as_deref(),
+            Some("serviceAccountEmail=auditor%40my-project.iam.gserviceaccount.com&showDeletedKeys=true")
+        );
+    }
+}
+
 #[cfg(all(test, feature = "global-client"))]
 mod tests {
     use super::*;
@@ -269,6 +321,24 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn list_with_deleted_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let key = HmacKey::create().await?;
+        remove_test_hmac(&key.metadata.access_id).await;
+        let list_request = HmacListRequest {
+            service_account_email: Some(key.metadata.service_account_email.clone()),
+            show_deleted_keys: Some(true),
+        };
+        let keys = crate::CLOUD_CLIENT
+            .hmac_key()
+            .list_with(&list_request)
+            .await?;
+        assert!(keys
+            .iter()
+            .any(|k| k.access_id == key.metadata.access_id && k.state == HmacState::Deleted));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn read() -> Result<(), Box<dyn std::error::Error>> {
         let key = get_test_hmac().await;
@@ -293,6 +363,23 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn rotate() -> Result<(), Box<dyn std::error::Error>> {
+        let client = crate::CLOUD_CLIENT.hmac_key();
+        let old = client.create().await?;
+        let rotation = client.rotate(&old.metadata.access_id).await?;
+        assert_eq!(rotation.previous.access_id, old.metadata.access_id);
+        assert_eq!(rotation.previous.state, HmacState::Active);
+        assert_eq!(rotation.key.metadata.state, HmacState::Active);
+        assert_eq!(
+            rotation.key.metadata.service_account_email,
+            old.metadata.service_account_email
+        );
+        remove_test_hmac(&rotation.previous.access_id).await;
+        remove_test_hmac(&rotation.key.metadata.access_id).await;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn clear_keys() -> Result<(), Box<dyn std::error::Error>> {
         let keys = HmacKey::list().await?;
@@ -8,6 +8,9 @@ pub enum Location {
     Multi(MultiRegion),
     /// Objects are stored redundantly accross two locations.
     Dual(DualRegion),
+    /// A location that is not (yet) known to this crate. Google regularly adds new locations, so
+    /// this variant ensures that buckets in those locations can still be read.
+    Other(String),
 }
 
 impl Default for Location {
@@ -16,6 +19,55 @@ impl Default for Location {
     }
 }
 
+impl Location {
+    /// The type of this location, or `None` for locations that are not known to this crate. The
+    /// `location_type` that Google reports for a bucket is also available for such locations.
+    pub fn location_type(&self) -> Option<LocationType> {
+        match self {
+            Location::Single(_) => Some(LocationType::Region),
+            Location::Multi(_) => Some(LocationType::MultiRegion),
+            Location::Dual(_) => Some(LocationType::DualRegion),
+            Location::Other(_) => None,
+        }
+    }
+}
+
+/// The type of location that a bucket resides in, which determines how its data is replicated.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LocationType {
+    /// A single region, such as `US-EAST1`.
+    Region,
+    /// A large area that contains multiple regions, such as `EU`.
+    MultiRegion,
+    /// A specific pair of regions, such as `NAM4`.
+    DualRegion,
+    /// A type of location that is not (yet) known to this crate.
+    Other(String),
+}
+
+impl From<String> for LocationType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "region" => LocationType::Region,
+            "multi-region" => LocationType::MultiRegion,
+            "dual-region" => LocationType::DualRegion,
+            _ => LocationType::Other(value),
+        }
+    }
+}
+
+impl From<LocationType> for String {
+    fn from(value: LocationType) -> Self {
+        match value {
+            LocationType::Region => "region".to_string(),
+            LocationType::MultiRegion => "multi-region".to_string(),
+            LocationType::DualRegion => "dual-region".to_string(),
+            LocationType::Other(value) => value,
+        }
+    }
+}
+
 /// The possible options for single regions.
 #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
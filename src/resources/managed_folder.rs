@@ -0,0 +1,173 @@
+/// A managed folder is a folder in a bucket with hierarchical namespace enabled, or a prefix in any
+/// bucket, that has its own IAM policy. Access to the objects in it can be granted on the managed
+/// folder, instead of on the whole bucket. See
+/// [Managed folders](https://cloud.google.com/storage/docs/managed-folders) for more information.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedFolder {
+    /// The kind of item this is. For managed folders, this is always `storage#managedFolder`.
+    pub kind: String,
+    /// The ID of the managed folder, including the bucket name and the managed folder name.
+    pub id: String,
+    /// The link to this managed folder.
+    pub self_link: String,
+    /// The name of the managed folder, which ends with a `/`, such as `reports/2024/`.
+    pub name: String,
+    /// The name of the bucket that contains the managed folder.
+    pub bucket: String,
+    /// The version of the metadata of the managed folder. Used for preconditions and for
+    /// detecting changes in metadata.
+    #[serde(deserialize_with = "crate::from_str")]
+    pub metageneration: i64,
+    /// The creation time of the managed folder.
+    pub create_time: chrono::DateTime<chrono::Utc>,
+    /// The modification time of the managed folder.
+    pub update_time: chrono::DateTime<chrono::Utc>,
+}
+
+/// The query parameters that can be supplied when listing managed folders.
+/// See [the Google Cloud Storage API
+/// reference](https://cloud.google.com/storage/docs/json_api/v1/managedFolders/list)
+/// for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRequest {
+    /// Only list the managed folders whose names begin with this prefix.
+    pub prefix: Option<String>,
+    /// The maximum number of managed folders that are returned in a single page of results. All
+    /// pages are requested either way.
+    pub page_size: Option<usize>,
+}
+
+/// The query parameters that can be supplied when deleting a managed folder.
+/// See [the Google Cloud Storage API
+/// reference](https://cloud.google.com/storage/docs/json_api/v1/managedFolders/delete)
+/// for more details.
+#[derive(Debug, PartialEq, serde::Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteParameters {
+    /// Allows the deletion of a managed folder that still contains objects, or other managed
+    /// folders. The objects themselves are not deleted.
+    pub allow_non_empty: Option<bool>,
+    /// Conditions on the metageneration of the managed folder that is deleted.
+    #[serde(flatten)]
+    pub preconditions: crate::bucket::Preconditions,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ManagedFolderList {
+    #[serde(default = "Vec::new")]
+    pub(crate) items: Vec<ManagedFolder>,
+    pub(crate) next_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod deserialize_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_managed_folder() -> Result<(), Box<dyn std::error::Error>> {
+        let response = r#"{
+            "kind": "storage#managedFolder",
+            "id": "my-bucket/reports/2024/",
+            "selfLink": "https://www.googleapis.com/storage/v1/b/my-bucket/managedFolders/reports%2F2024%2F",
+            "name": "reports/2024/",
+            "bucket": "my-bucket",
+            "metageneration": "3",
+            "createTime": "2024-03-29T06:40:00.000Z",
+            "updateTime": "2024-03-30T06:40:00.000Z"
+        }"#;
+        let folder: ManagedFolder = serde_json::from_str(response)?;
+        assert_eq!(folder.name, "reports/2024/");
+        assert_eq!(folder.metageneration, 3);
+
+        let list: ManagedFolderList =
+            serde_json::from_str(r#"{"kind": "storage#managedFolders"}"#)?;
+        assert!(list.items.is_empty());
+        assert_eq!(list.next_page_token, None);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod request_tests {
+    use super::*;
+
+    #[test]
+    fn delete_parameters_in_query() {
+        let parameters = DeleteParameters {
+            allow_non_empty: Some(true),
+            preconditions: crate::bucket::Preconditions {
+                if_metageneration_match: Some(2),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            crate::query_of(&parameters).as_deref(),
+            Some("allowNonEmpty=true&ifMetagenerationMatch=2")
+        );
+    }
+}
+
+#[cfg(all(test, feature = "global-client"))]
+mod tests {
+    use super::*;
+    use crate::bucket::{IamConfiguration, NewBucket, UniformBucketLevelAccess};
+
+    #[tokio::test]
+    async fn create_list_read_delete() -> Result<(), Box<dyn std::error::Error>> {
+        dotenv::dotenv().ok();
+        let base_name = std::env::var("TEST_BUCKET")?;
+        // managed folders can only be created in buckets with uniform bucket-level access.
+        let new_bucket = NewBucket {
+            name: format!("{}-test-managed-folder", base_name),
+            iam_configuration: Some(IamConfiguration {
+                uniform_bucket_level_access: UniformBucketLevelAccess {
+                    enabled: true,
+                    locked_time: None,
+                },
+            }),
+            ..Default::default()
+        };
+        let buckets = crate::CLOUD_CLIENT.bucket();
+        let bucket = match buckets.create(&new_bucket).await {
+            Ok(bucket) => bucket,
+            Err(_already_exists) => buckets.read(&new_bucket.name).await?,
+        };
+        let client = crate::CLOUD_CLIENT.managed_folder();
+
+        let folder = client.create(&bucket.name, "reports/2024/").await?;
+        assert_eq!(folder.name, "reports/2024/");
+        let list_request = ListRequest {
+            prefix: Some("reports/".to_string()),
+            ..Default::default()
+        };
+        let folders = client.list(&bucket.name, &list_request).await?;
+        assert!(folders.iter().any(|f| f.name == folder.name));
+        let read = client.read(&bucket.name, &folder.name).await?;
+        assert_eq!(read.metageneration, folder.metageneration);
+
+        let stale = DeleteParameters {
+            preconditions: crate::bucket::Preconditions {
+                if_metageneration_not_match: Some(folder.metageneration),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = client.delete(&bucket.name, &folder.name, &stale).await;
+        assert!(result.unwrap_err().is_precondition_failed());
+        let current = DeleteParameters {
+            preconditions: crate::bucket::Preconditions {
+                if_metageneration_match: Some(folder.metageneration),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        client.delete(&bucket.name, &folder.name, &current).await?;
+        assert!(client.read(&bucket.name, &folder.name).await.is_err());
+
+        buckets.delete(bucket).await?;
+        Ok(())
+    }
+}
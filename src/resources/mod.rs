@@ -15,6 +15,8 @@ mod location;
 // /// A subscription to receive
 // /// [Pub/Sub notifications](https://cloud.google.com/storage/docs/pubsub-notifications).
 // pub mod notification;
+/// A folder with its own IAM policy, in a bucket with hierarchical namespace enabled.
+pub mod managed_folder;
 /// A file
 pub mod object;
 /// Contains data about to access specific files.
@@ -22,7 +24,5 @@ pub mod object_access_control;
 /// A deserialized version of the `service-account-********.json` file. Used to authenticate
 /// requests.
 pub mod service_account;
-/// Used for parsing the `service-account-********.json` file.
-pub(crate) mod signature;
-/// The topic field of a `Notification`
-mod topic;
+// /// The topic field of a `Notification`
+// mod topic;
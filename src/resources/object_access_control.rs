@@ -20,7 +20,7 @@ use crate::{error::GoogleResponse, resources::common::ListResponse};
 ///
 /// For more information, see Access Control, with the caveat that this API uses READER and OWNER
 /// instead of READ and FULL_CONTROL.
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectAccessControl {
     /// The kind of item this is. For object access control entries, this is always
@@ -326,6 +326,39 @@ mod tests {
         bucket.delete().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn set_all() {
+        // use a seperate bucket to prevent synchronization issues
+        let bucket = crate::create_test_bucket("test-object-access-controls-set-all").await;
+        Object::create(&bucket.name, vec![0, 1], "test-set-all", "text/plain")
+            .await
+            .unwrap();
+        let mut acl: Vec<_> = crate::CLOUD_CLIENT
+            .object_access_control()
+            .list_all(&bucket.name, "test-set-all")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| NewObjectAccessControl {
+                entity: entry.entity,
+                role: entry.role,
+            })
+            .collect();
+        acl.push(NewObjectAccessControl {
+            entity: Entity::AllUsers,
+            role: Role::Reader,
+        });
+        let result = crate::CLOUD_CLIENT
+            .object_access_control()
+            .set_all(&bucket.name, "test-set-all", &acl)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), acl.len());
+        assert!(result.iter().any(|entry| entry.entity == Entity::AllUsers));
+        Object::delete(&bucket.name, "test-set-all").await.unwrap();
+        bucket.delete().await.unwrap();
+    }
+
     #[tokio::test]
     async fn delete() {
         // use a seperate bucket to prevent synchronization issues
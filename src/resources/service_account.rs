@@ -25,22 +25,48 @@ pub struct ServiceAccount {
 }
 
 impl ServiceAccount {
+    /// Parses the contents of a `service-account-********.json`-file. This can be used to work
+    /// with a service account other than the one that is configured through the environment.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let account: Self = serde_json::from_str(json)?;
+        if account.r#type != "service_account" {
+            let msg = format!(
+                "`type` parameter of service account is not 'service_account', but '{}'",
+                account.r#type
+            );
+            return Err(crate::Error::Other(msg));
+        }
+        Ok(account)
+    }
+
     pub(crate) fn get() -> Self {
+        Self::from_env().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // Reads the service account that is configured through the environment, see `Client::new`.
+    pub(crate) fn from_env() -> Result<Self, String> {
         dotenv::dotenv().ok();
-        let credentials_json = std::env::var("SERVICE_ACCOUNT")
+        let credentials_json = match std::env::var("SERVICE_ACCOUNT")
             .or_else(|_| std::env::var("GOOGLE_APPLICATION_CREDENTIALS"))
-            .map(|path| std::fs::read_to_string(path).expect("SERVICE_ACCOUNT file not found"))
-            .or_else(|_| std::env::var("SERVICE_ACCOUNT_JSON"))
-            .or_else(|_| std::env::var("GOOGLE_APPLICATION_CREDENTIALS_JSON"))
-            .expect(
-                "SERVICE_ACCOUNT(_JSON) or GOOGLE_APPLICATION_CREDENTIALS(_JSON) environment parameter required",
+        {
+            Ok(path) => std::fs::read_to_string(path)
+                .map_err(|e| format!("SERVICE_ACCOUNT file not found: {}", e))?,
+            Err(_) => std::env::var("SERVICE_ACCOUNT_JSON")
+                .or_else(|_| std::env::var("GOOGLE_APPLICATION_CREDENTIALS_JSON"))
+                .map_err(|_| {
+                    "SERVICE_ACCOUNT(_JSON) or GOOGLE_APPLICATION_CREDENTIALS(_JSON) environment \
+                    parameter required"
+                        .to_string()
+                })?,
+        };
+        let account: Self = serde_json::from_str(&credentials_json)
+            .map_err(|e| format!("SERVICE_ACCOUNT file not valid: {}", e))?;
+        if account.r#type != "service_account" {
+            return Err(
+                "`type` parameter of `SERVICE_ACCOUNT` variable is not 'service_account'"
+                    .to_string(),
             );
-        let account: Self =
-            serde_json::from_str(&credentials_json).expect("SERVICE_ACCOUNT file not valid");
-        assert_eq!(
-            account.r#type, "service_account",
-            "`type` parameter of `SERVICE_ACCOUNT` variable is not 'service_account'"
-        );
-        account
+        }
+        Ok(account)
     }
 }
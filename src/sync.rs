@@ -4,6 +4,7 @@ mod bucket;
 mod bucket_access_control;
 mod default_object_access_control;
 mod hmac_key;
+mod managed_folder;
 mod object;
 mod object_access_control;
 
@@ -13,6 +14,7 @@ pub use bucket::BucketClient;
 pub use bucket_access_control::BucketAccessControlClient;
 pub use default_object_access_control::DefaultObjectAccessControlClient;
 pub use hmac_key::HmacKeyClient;
+pub use managed_folder::ManagedFolderClient;
 pub use object::ObjectClient;
 pub use object_access_control::ObjectAccessControlClient;
 
@@ -28,10 +30,10 @@ impl Client {
     /// credentials from the following locations:
     ///
     /// 1. Checks for the environment variable `SERVICE_ACCOUNT`, and if it exists, reads the file
-    /// at the path specified there as a credentials json file.
+    ///    at the path specified there as a credentials json file.
     /// 2. It attemps to do the same with the `GOOGLE_APPLICATION_CREDENTIALS` var.
     /// 3. It reads the `SERVICE_ACCOUNT_JSON` environment variable directly as json and uses that
-    /// 4.It attemps to do the same with the `GOOGLE_APPLICATION_CREDENTIALS_JSON` var.
+    /// 4. It attemps to do the same with the `GOOGLE_APPLICATION_CREDENTIALS_JSON` var.
     pub fn new() -> crate::Result<Self> {
         Ok(Self {
             runtime: crate::runtime()?,
@@ -47,33 +49,69 @@ impl Client {
         })
     }
 
+    /// Constructs a client that does not authenticate, which can only access public buckets and
+    /// objects. See [`crate::Client::anonymous`].
+    pub fn anonymous() -> crate::Result<Self> {
+        Ok(Self {
+            runtime: crate::runtime()?,
+            client: crate::Client::anonymous(),
+        })
+    }
+
+    /// Wraps an already configured asynchronous `Client`. See
+    /// [`ClientBuilder::build_sync`](crate::client::ClientBuilder::build_sync) for a convenient way
+    /// to construct a synchronous client with non-default settings.
+    pub fn from_client(client: crate::Client) -> crate::Result<Self> {
+        Ok(Self {
+            runtime: crate::runtime()?,
+            client,
+        })
+    }
+
     /// Synchronous operations on [`Bucket`](crate::bucket::Bucket)s.
-    pub fn bucket(&self) -> BucketClient {
+    pub fn bucket(&self) -> BucketClient<'_> {
         BucketClient(self)
     }
 
     /// Synchronous operations on [`BucketAccessControl`](crate::bucket_access_control::BucketAccessControl)s.
-    pub fn bucket_access_control(&self) -> BucketAccessControlClient {
+    pub fn bucket_access_control(&self) -> BucketAccessControlClient<'_> {
         BucketAccessControlClient(self)
     }
 
     /// Synchronous operations on [`DefaultObjectAccessControl`](crate::default_object_access_control::DefaultObjectAccessControl)s.
-    pub fn default_object_access_control(&self) -> DefaultObjectAccessControlClient {
+    pub fn default_object_access_control(&self) -> DefaultObjectAccessControlClient<'_> {
         DefaultObjectAccessControlClient(self)
     }
 
     /// Synchronous operations on [`HmacKey`](crate::hmac_key::HmacKey)s.
-    pub fn hmac_key(&self) -> HmacKeyClient {
+    pub fn hmac_key(&self) -> HmacKeyClient<'_> {
         HmacKeyClient(self)
     }
 
+    /// Synchronous operations on [`ManagedFolder`](crate::managed_folder::ManagedFolder)s.
+    pub fn managed_folder(&self) -> ManagedFolderClient<'_> {
+        ManagedFolderClient(self)
+    }
+
     /// Synchronous operations on [`Object`](crate::object::Object)s.
-    pub fn object(&self) -> ObjectClient {
+    pub fn object(&self) -> ObjectClient<'_> {
         ObjectClient(self)
     }
 
     /// Synchronous operations on [`ObjectAccessControl`](crate::object_access_control::ObjectAccessControl)s.
-    pub fn object_access_control(&self) -> ObjectAccessControlClient {
+    pub fn object_access_control(&self) -> ObjectAccessControlClient<'_> {
         ObjectAccessControlClient(self)
     }
+
+    /// Returns the email address of the service account that this client operates as. See
+    /// [`crate::Client::service_account_email`].
+    pub fn service_account_email(&self) -> crate::Result<&str> {
+        self.client.service_account_email()
+    }
+
+    /// Returns the project of the service account that this client operates as. See
+    /// [`crate::Client::project_id`].
+    pub fn project_id(&self) -> crate::Result<&str> {
+        self.client.project_id()
+    }
 }
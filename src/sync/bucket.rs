@@ -1,5 +1,8 @@
 use crate::{
-    bucket::{IamPolicy, TestIamPermission},
+    bucket::{
+        IamPolicy, Lifecycle, ReadParameters, Rpo, TestIamPermission, UpdateParameters, Website,
+    },
+    object::PredefinedAcl,
     Bucket, NewBucket,
 };
 
@@ -80,6 +83,25 @@ impl<'a> BucketClient<'a> {
         self.0.runtime.block_on(self.0.client.bucket().read(name))
     }
 
+    /// Returns a single `Bucket` by its name, using the provided `ReadParameters`. If
+    /// `if_metageneration_not_match` is not met, `None` is returned, while an unmet
+    /// `if_metageneration_match` results in `Error::PreconditionFailed`.
+    pub fn read_with(
+        &self,
+        name: &str,
+        parameters: &ReadParameters,
+    ) -> crate::Result<Option<Bucket>> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().read_with(name, parameters))
+    }
+
+    /// Checks whether a bucket with the specified name exists. A missing bucket results in
+    /// `false`, while any other failure is returned as an error.
+    pub fn exists(&self, name: &str) -> crate::Result<bool> {
+        self.0.runtime.block_on(self.0.client.bucket().exists(name))
+    }
+
     /// Update an existing `Bucket`. If you declare you bucket as mutable, you can edit its fields.
     /// You can then flush your changes to Google Cloud Storage using this method.
     /// ### Example
@@ -113,6 +135,127 @@ impl<'a> BucketClient<'a> {
             .block_on(self.0.client.bucket().update(bucket))
     }
 
+    /// Updates a bucket in the same way as `update`, using the query parameters in `parameters`.
+    /// See [`crate::client::BucketClient::update_with`].
+    pub fn update_with(
+        &self,
+        bucket: &Bucket,
+        parameters: &UpdateParameters,
+    ) -> crate::Result<Bucket> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().update_with(bucket, parameters))
+    }
+
+    /// Configures the bucket with the specified name to serve its contents as a static website,
+    /// using the index and 404 pages in `website`. Only the `website` field of the bucket is
+    /// changed.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::bucket::Website;
+    ///
+    /// let client = Client::new()?;
+    /// let website = Website {
+    ///     main_page_suffix: "index.html".to_string(),
+    ///     not_found_page: "404.html".to_string(),
+    /// };
+    /// client.bucket().set_website("my-bucket", &website)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_website(&self, name: &str, website: &Website) -> crate::Result<Bucket> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().set_website(name, website))
+    }
+
+    /// Removes the static website configuration from the bucket with the specified name. Only
+    /// the `website` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// client.bucket().clear_website("my-bucket")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clear_website(&self, name: &str) -> crate::Result<Bucket> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().clear_website(name))
+    }
+
+    /// Replaces the lifecycle rules of the bucket with the specified name with `lifecycle`. Only
+    /// the `lifecycle` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::bucket::Lifecycle;
+    ///
+    /// let client = Client::new()?;
+    /// let lifecycle = Lifecycle::delete_after_days(30);
+    /// client.bucket().set_lifecycle("my-bucket", &lifecycle)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_lifecycle(&self, name: &str, lifecycle: &Lifecycle) -> crate::Result<Bucket> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().set_lifecycle(name, lifecycle))
+    }
+
+    /// Sets the recovery point objective of the dual-region bucket with the specified name to
+    /// `rpo`. Only the `rpo` field of the bucket is changed.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::bucket::Rpo;
+    ///
+    /// let client = Client::new()?;
+    /// client.bucket().set_rpo("my-dual-region-bucket", Rpo::AsyncTurbo)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_rpo(&self, name: &str, rpo: Rpo) -> crate::Result<Bucket> {
+        self.0
+            .runtime
+            .block_on(self.0.client.bucket().set_rpo(name, rpo))
+    }
+
+    /// Sets the default access controls of new objects in the bucket with the specified name to
+    /// the predefined set `acl`. Objects that already exist keep their access controls.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::object::PredefinedAcl;
+    ///
+    /// let client = Client::new()?;
+    /// client
+    ///     .bucket()
+    ///     .set_predefined_default_object_acl("my-bucket", PredefinedAcl::PublicRead)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_predefined_default_object_acl(
+        &self,
+        name: &str,
+        acl: PredefinedAcl,
+    ) -> crate::Result<Bucket> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .bucket()
+                .set_predefined_default_object_acl(name, acl),
+        )
+    }
+
     /// Delete an existing `Bucket`. This permanently removes a bucket from Google Cloud Storage.
     /// An error is returned when you don't have sufficient permissions, or when the
     /// `retention_policy` prevents you from deleting your Bucket.
@@ -232,4 +375,32 @@ impl<'a> BucketClient<'a> {
                 .test_iam_permission(bucket, permission),
         )
     }
+
+    /// Checks which of `permissions` the user provided in the service account has, in a single
+    /// request, and returns the ones it has.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let bucket = client.bucket().read("my-bucket")?;
+    /// let granted = client
+    ///     .bucket()
+    ///     .test_iam_permissions(&bucket, &["storage.objects.get", "storage.objects.list"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn test_iam_permissions(
+        &self,
+        bucket: &Bucket,
+        permissions: &[&str],
+    ) -> crate::Result<Vec<String>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .bucket()
+                .test_iam_permissions(bucket, permissions),
+        )
+    }
 }
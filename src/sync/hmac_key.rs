@@ -1,4 +1,4 @@
-use crate::hmac_key::{HmacKey, HmacMeta, HmacState};
+use crate::hmac_key::{HmacKey, HmacListRequest, HmacMeta, HmacRotation, HmacState};
 
 /// Operations on [`HmacKey`](HmacKey)s.
 #[derive(Debug)]
@@ -30,6 +30,28 @@ impl<'a> HmacKeyClient<'a> {
         self.0.runtime.block_on(self.0.client.hmac_key().create())
     }
 
+    /// Starts the rotation of the HMAC key with the id `access_id`, by creating a new key for the
+    /// same service account. See [`crate::client::HmacKeyClient::rotate`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::hmac_key::HmacState;
+    ///
+    /// let client = Client::new()?;
+    /// let rotation = client.hmac_key().rotate("old access id")?;
+    /// let old = &rotation.previous.access_id;
+    /// client.hmac_key().update(old, HmacState::Inactive)?;
+    /// client.hmac_key().delete(old)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rotate(&self, access_id: &str) -> crate::Result<HmacRotation> {
+        self.0
+            .runtime
+            .block_on(self.0.client.hmac_key().rotate(access_id))
+    }
+
     /// Retrieves a list of HMAC keys matching the criteria. Since the HmacKey is secret, this does
     /// not return a `HmacKey`, but a `HmacMeta`. This is a redacted version of a `HmacKey`, but
     /// with the secret data omitted.
@@ -54,6 +76,14 @@ impl<'a> HmacKeyClient<'a> {
         self.0.runtime.block_on(self.0.client.hmac_key().list())
     }
 
+    /// Retrieves a list of HMAC keys, in the same way as `list`, filtered by the provided
+    /// `HmacListRequest`.
+    pub fn list_with(&self, list_request: &HmacListRequest) -> crate::Result<Vec<HmacMeta>> {
+        self.0
+            .runtime
+            .block_on(self.0.client.hmac_key().list_with(list_request))
+    }
+
     /// Retrieves an HMAC key's metadata. Since the HmacKey is secret, this does not return a
     /// `HmacKey`, but a `HmacMeta`. This is a redacted version of a `HmacKey`, but with the secret
     /// data omitted.
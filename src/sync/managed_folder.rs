@@ -0,0 +1,103 @@
+use crate::{
+    bucket::{IamPolicy, TestIamPermission},
+    managed_folder::{DeleteParameters, ListRequest, ManagedFolder},
+};
+
+/// Operations on [`ManagedFolder`](ManagedFolder)s.
+#[derive(Debug)]
+pub struct ManagedFolderClient<'a>(pub(super) &'a super::Client);
+
+impl<'a> ManagedFolderClient<'a> {
+    /// Creates a managed folder with the specified name in the specified bucket. See
+    /// [`crate::client::ManagedFolderClient::create`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let folder = client.managed_folder().create("my-bucket", "reports/2024/")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, bucket: &str, name: &str) -> crate::Result<ManagedFolder> {
+        self.0
+            .runtime
+            .block_on(self.0.client.managed_folder().create(bucket, name))
+    }
+
+    /// Returns the managed folder with the specified name in the specified bucket.
+    pub fn read(&self, bucket: &str, name: &str) -> crate::Result<ManagedFolder> {
+        self.0
+            .runtime
+            .block_on(self.0.client.managed_folder().read(bucket, name))
+    }
+
+    /// Returns all managed folders in the specified bucket that match `list_request`. See
+    /// [`crate::client::ManagedFolderClient::list`].
+    pub fn list(
+        &self,
+        bucket: &str,
+        list_request: &ListRequest,
+    ) -> crate::Result<Vec<ManagedFolder>> {
+        self.0
+            .runtime
+            .block_on(self.0.client.managed_folder().list(bucket, list_request))
+    }
+
+    /// Deletes the managed folder with the specified name in the specified bucket. See
+    /// [`crate::client::ManagedFolderClient::delete`].
+    pub fn delete(
+        &self,
+        bucket: &str,
+        name: &str,
+        parameters: &DeleteParameters,
+    ) -> crate::Result<()> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .managed_folder()
+                .delete(bucket, name, parameters),
+        )
+    }
+
+    /// Returns the IAM Policy of the managed folder with the specified name in the specified
+    /// bucket.
+    pub fn get_iam_policy(&self, bucket: &str, name: &str) -> crate::Result<IamPolicy> {
+        self.0
+            .runtime
+            .block_on(self.0.client.managed_folder().get_iam_policy(bucket, name))
+    }
+
+    /// Replaces the IAM Policy of the managed folder with the specified name in the specified
+    /// bucket.
+    pub fn set_iam_policy(
+        &self,
+        bucket: &str,
+        name: &str,
+        iam: &IamPolicy,
+    ) -> crate::Result<IamPolicy> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .managed_folder()
+                .set_iam_policy(bucket, name, iam),
+        )
+    }
+
+    /// Checks whether the user provided in the service account has `permission` on the managed
+    /// folder with the specified name in the specified bucket.
+    pub fn test_iam_permission(
+        &self,
+        bucket: &str,
+        name: &str,
+        permission: &str,
+    ) -> crate::Result<TestIamPermission> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .managed_folder()
+                .test_iam_permission(bucket, name, permission),
+        )
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
-    object::{ComposeRequest, ObjectList},
-    ListRequest, Object,
+    object::{
+        ComposeParameters, ComposeRequest, CopyParameters, ListEntry, ObjectList, ObjectRetention,
+        Preconditions, ReadParameters, RewriteParameters, SourceObject, Usage,
+    },
+    ListRequest, Object, UploadOptions,
 };
 use futures_util::TryStreamExt;
 
@@ -40,6 +43,71 @@ impl<'a> ObjectClient<'a> {
         )
     }
 
+    /// Creates a new object in the same way as `create`, and returns it together with a signed
+    /// url that lets anyone download it without authentication for `duration` seconds.
+    pub fn create_and_sign(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+        duration: u32,
+    ) -> crate::Result<(Object, String)> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .create_and_sign(bucket, file, filename, mime_type, duration),
+        )
+    }
+
+    /// Create a new object, using the provided `UploadOptions`. These can be used to compress the
+    /// file before it is uploaded.
+    /// ## Example
+    /// ```rust,no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::{sync::Client, UploadOptions};
+    ///
+    /// let logs = b"a lot of repetitive log lines".to_vec();
+    /// let client = Client::new()?;
+    /// let options = UploadOptions::new().gzip(true);
+    /// client.object().create_with("logs", logs, "today.log", "text/plain", options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_with(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+        options: UploadOptions,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .create_with(bucket, file, filename, mime_type, options),
+        )
+    }
+
+    /// Create a new object, but only if no object with the same name exists yet in the bucket.
+    /// Returns `None` if the object already exists.
+    pub fn create_if_absent(
+        &self,
+        bucket: &str,
+        file: Vec<u8>,
+        filename: &str,
+        mime_type: &str,
+    ) -> crate::Result<Option<Object>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .create_if_absent(bucket, file, filename, mime_type),
+        )
+    }
+
     /// Create a new object. This works in the same way as `ObjectClient::create`, except it does not need
     /// to load the entire file in ram.
     pub fn create_streamed<R>(
@@ -63,6 +131,153 @@ impl<'a> ObjectClient<'a> {
         )
     }
 
+    /// Create a new object from the contents of the local file at `path`, without loading the
+    /// entire file in ram. See [`crate::client::ObjectClient::create_from_file`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// client
+    ///     .object()
+    ///     .create_from_file("cat-photos", "photos/cat.png", "cat.png", "image/png")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_from_file(
+        &self,
+        bucket: &str,
+        path: impl AsRef<std::path::Path>,
+        filename: &str,
+        mime_type: &str,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .create_from_file(bucket, path, filename, mime_type),
+        )
+    }
+
+    /// Create a new object with the provided metadata. This works in the same way as
+    /// `ObjectClient::create_streamed`, except that `metadata` is sent along in the same request.
+    /// `metadata` is a (partial) object resource, so custom metadata should be nested under the
+    /// `metadata` key.
+    /// ## Example
+    /// ```rust,no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let file = std::fs::File::open("cat.png")?;
+    /// let length = file.metadata()?.len();
+    /// let metadata = serde_json::json!({ "metadata": { "photographer": "Alice" } });
+    /// client
+    ///     .object()
+    ///     .create_streamed_with("cat-photos", file, length, "cat.png", "image/png", &metadata)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_streamed_with<R>(
+        &self,
+        bucket: &str,
+        file: R,
+        length: impl Into<Option<u64>>,
+        filename: &str,
+        mime_type: &str,
+        metadata: &serde_json::Value,
+    ) -> crate::Result<Object>
+    where
+        R: std::io::Read + Send + Sync + Unpin + 'static,
+    {
+        let stream = super::helpers::ReaderStream::new(file);
+
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .create_streamed_with(bucket, stream, length, filename, mime_type, metadata),
+        )
+    }
+
+    /// Starts a resumable upload, and returns the URI of the upload session. See
+    /// [`crate::client::ObjectClient::start_resumable_upload`].
+    pub fn start_resumable_upload(
+        &self,
+        bucket: &str,
+        filename: &str,
+        mime_type: &str,
+        length: impl Into<Option<u64>>,
+        metadata: &serde_json::Value,
+    ) -> crate::Result<String> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .start_resumable_upload(bucket, filename, mime_type, length, metadata),
+        )
+    }
+
+    /// Sends the content of a resumable upload from `file`. See
+    /// [`crate::client::ObjectClient::upload_resumable`].
+    pub fn upload_resumable<R>(
+        &self,
+        session_uri: &str,
+        file: R,
+        length: impl Into<Option<u64>>,
+        max_retries: u32,
+    ) -> crate::Result<Object>
+    where
+        R: std::io::Read + Send + Sync + Unpin + 'static,
+    {
+        let stream = super::helpers::ReaderStream::new(file);
+
+        self.0
+            .runtime
+            .block_on(self.0.client.object().upload_resumable(
+                session_uri,
+                stream,
+                length,
+                max_retries,
+            ))
+    }
+
+    /// Cancels a resumable upload session, discarding the content it has received. See
+    /// [`crate::client::ObjectClient::abort_resumable_session`].
+    pub fn abort_resumable_session(&self, session_uri: &str) -> crate::Result<()> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().abort_resumable_session(session_uri))
+    }
+
+    /// Uploads all files in `local_dir` and its subdirectories to the specified bucket. See
+    /// [`crate::client::ObjectClient::upload_dir`] for how the objects are named.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let results = client.object().upload_dir("public", "my_bucket", "site/", 8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_dir(
+        &self,
+        local_dir: impl AsRef<std::path::Path>,
+        bucket: &str,
+        prefix: &str,
+        concurrency: usize,
+    ) -> crate::Result<Vec<(std::path::PathBuf, crate::Result<Object>)>> {
+        self.0.runtime.block_on(self.0.client.object().upload_dir(
+            local_dir,
+            bucket,
+            prefix,
+            concurrency,
+        ))
+    }
+
     /// Obtain a list of objects within this Bucket.
     /// ### Example
     /// ```no_run
@@ -85,6 +300,104 @@ impl<'a> ObjectClient<'a> {
         rt.block_on(listed.try_collect())
     }
 
+    /// Obtain the objects within this Bucket for which `predicate` returns `true`.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::ListRequest;
+    ///
+    /// let client = Client::new()?;
+    /// let yesterday = chrono::Utc::now() - chrono::Duration::hours(24);
+    /// let recently_updated = client
+    ///     .object()
+    ///     .list_filtered("my_bucket", ListRequest::default(), move |object| {
+    ///         object.updated > yesterday
+    ///     })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_filtered<F>(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+        predicate: F,
+    ) -> crate::Result<Vec<Object>>
+    where
+        F: Fn(&Object) -> bool + 'a,
+    {
+        let rt = &self.0.runtime;
+        let listed = rt.block_on(self.0.client.object().list_filtered(
+            bucket,
+            list_request,
+            predicate,
+        ))?;
+        rt.block_on(listed.try_collect())
+    }
+
+    /// Obtain the objects and prefixes within this Bucket as a single list of entries. See
+    /// [`crate::client::ObjectClient::list_entries`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use cloud_storage::ListRequest;
+    ///
+    /// let client = Client::new()?;
+    /// let list_request = ListRequest {
+    ///     delimiter: Some("/".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let entries = client.object().list_entries("my_bucket", list_request)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_entries(
+        &self,
+        bucket: &'a str,
+        list_request: ListRequest,
+    ) -> crate::Result<Vec<ListEntry>> {
+        let rt = &self.0.runtime;
+        let listed = rt.block_on(self.0.client.object().list_entries(bucket, list_request))?;
+        rt.block_on(listed.try_collect())
+    }
+
+    /// Counts the objects within this Bucket whose names start with `prefix`, and adds up their
+    /// sizes. See [`crate::client::ObjectClient::usage`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let usage = client.object().usage("my_bucket", "logs/")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn usage(&self, bucket: &str, prefix: &str) -> crate::Result<Usage> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().usage(bucket, prefix))
+    }
+
+    /// Obtains every generation of the object with the specified name in the specified bucket,
+    /// sorted from oldest to newest. See [`crate::client::ObjectClient::versions`].
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let history = client.object().versions("my_bucket", "config.json")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn versions(&self, bucket: &'a str, file_name: &str) -> crate::Result<Vec<Object>> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().versions(bucket, file_name))
+    }
+
     /// Obtains a single object with the specified name in the specified bucket.
     /// ### Example
     /// ```no_run
@@ -103,6 +416,52 @@ impl<'a> ObjectClient<'a> {
             .block_on(self.0.client.object().read(bucket, file_name))
     }
 
+    /// Obtains a single object with the specified name in the specified bucket, using the provided
+    /// `ReadParameters`. These can be used to control whether the acl of the object is included.
+    pub fn read_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .read_with(bucket, file_name, parameters),
+        )
+    }
+
+    /// Waits until an object with the specified name exists in the specified bucket, and returns
+    /// it. See [`crate::client::ObjectClient::wait_for`] for the polling behaviour.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new()?;
+    /// let object = client
+    ///     .object()
+    ///     .wait_for("my_bucket", "path/to/my/file.png", Duration::from_millis(500), Duration::from_secs(60))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(self.0.client.object().wait_for(
+            bucket,
+            file_name,
+            poll_interval,
+            timeout,
+        ))
+    }
+
     /// Download the content of the object with the specified name in the specified bucket.
     /// ### Example
     /// ```no_run
@@ -121,6 +480,66 @@ impl<'a> ObjectClient<'a> {
             .block_on(self.0.client.object().download(bucket, file_name))
     }
 
+    /// Download the content of the object with the specified name in the specified bucket, using
+    /// the provided `ReadParameters`.
+    pub fn download_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        parameters: ReadParameters,
+    ) -> crate::Result<Vec<u8>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .download_with(bucket, file_name, parameters),
+        )
+    }
+
+    /// Download the content of `object`, using its `media_link`. This fetches exactly the
+    /// generation that `object` describes.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let object = client.object().read("my_bucket", "path/to/my/file.png")?;
+    /// let bytes = client.object().download_object(&object)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_object(&self, object: &Object) -> crate::Result<Vec<u8>> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().download_object(object))
+    }
+
+    /// Obtains both the metadata and the content of the object with the specified name in the
+    /// specified bucket. The content is downloaded from the exact generation that the metadata
+    /// describes.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// let (object, bytes) = client
+    ///     .object()
+    ///     .read_and_download("my_bucket", "path/to/my/config.json")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_and_download(
+        &self,
+        bucket: &str,
+        file_name: &str,
+    ) -> crate::Result<(Object, Vec<u8>)> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().read_and_download(bucket, file_name))
+    }
+
     /// Obtains a single object with the specified name in the specified bucket.
     /// ### Example
     /// ```no_run
@@ -141,6 +560,190 @@ impl<'a> ObjectClient<'a> {
             .block_on(self.0.client.object().update(object))
     }
 
+    /// Updates a single object in the same way as `update`, but only if the object still meets
+    /// `preconditions`.
+    pub fn update_with(
+        &self,
+        object: &Object,
+        preconditions: &Preconditions,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().update_with(object, preconditions))
+    }
+
+    /// Places or releases a temporary hold on the object with the specified name in the specified
+    /// bucket. Only the `temporaryHold` field is patched, so other metadata of the object is left
+    /// untouched.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// client.object().set_temporary_hold("my_bucket", "path/to/my/file.png", true)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_temporary_hold(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        hold: bool,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .set_temporary_hold(bucket, file_name, hold),
+        )
+    }
+
+    /// Places or releases an event-based hold on the object with the specified name in the
+    /// specified bucket. Only the `eventBasedHold` field is patched, so other metadata of the
+    /// object is left untouched.
+    /// ### Example
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::sync::Client;
+    ///
+    /// let client = Client::new()?;
+    /// client.object().set_event_based_hold("my_bucket", "path/to/my/file.png", false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_event_based_hold(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        hold: bool,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .set_event_based_hold(bucket, file_name, hold),
+        )
+    }
+
+    /// Sets the custom time of the object with the specified name in the specified bucket. Lifecycle
+    /// rules can use the `days_since_custom_time` condition to act on this timestamp. Once set, the
+    /// custom time can only be moved forward. Other metadata of the object is left untouched.
+    pub fn set_custom_time(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        custom_time: chrono::DateTime<chrono::Utc>,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(
+                self.0
+                    .client
+                    .object()
+                    .set_custom_time(bucket, file_name, custom_time),
+            )
+    }
+
+    /// Sets the `Content-Disposition` header that Google serves along with the content of the
+    /// object. Other metadata of the object is left untouched.
+    pub fn set_content_disposition(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        content_disposition: &str,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().set_content_disposition(
+                bucket,
+                file_name,
+                content_disposition,
+            ))
+    }
+
+    /// Sets the `Content-Language` header that Google serves along with the content of the object.
+    /// Other metadata of the object is left untouched.
+    pub fn set_content_language(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        content_language: &str,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().set_content_language(
+                bucket,
+                file_name,
+                content_language,
+            ))
+    }
+
+    /// Sets the `Cache-Control` header that Google serves along with the content of the object.
+    /// Other metadata of the object is left untouched.
+    pub fn set_cache_control(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        cache_control: &str,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(
+                self.0
+                    .client
+                    .object()
+                    .set_cache_control(bucket, file_name, cache_control),
+            )
+    }
+
+    /// Sets the `Cache-Control` header of all objects in the specified bucket whose names start
+    /// with `prefix`, and returns how many were updated. See
+    /// [`crate::client::ObjectClient::set_cache_control_prefix`].
+    pub fn set_cache_control_prefix(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        cache_control: &str,
+        concurrency: usize,
+    ) -> crate::Result<usize> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().set_cache_control_prefix(
+                bucket,
+                prefix,
+                cache_control,
+                concurrency,
+            ))
+    }
+
+    /// Sets the retention configuration of the object with the specified name in the specified
+    /// bucket. See [`crate::client::ObjectClient::set_retention`].
+    pub fn set_retention(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        retention: ObjectRetention,
+        override_unlocked_retention: bool,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().set_retention(
+                bucket,
+                file_name,
+                retention,
+                override_unlocked_retention,
+            ))
+    }
+
+    /// Removes the unlocked retention configuration of the object with the specified name in the
+    /// specified bucket. See [`crate::client::ObjectClient::clear_retention`].
+    pub fn clear_retention(&self, bucket: &str, file_name: &str) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().clear_retention(bucket, file_name))
+    }
+
     /// Deletes a single object with the specified name in the specified bucket.
     /// ### Example
     /// ```no_run
@@ -159,6 +762,56 @@ impl<'a> ObjectClient<'a> {
             .block_on(self.0.client.object().delete(bucket, file_name))
     }
 
+    /// Deletes a single object with the specified name in the specified bucket, but only if it
+    /// meets `preconditions`.
+    pub fn delete_with(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        preconditions: &Preconditions,
+    ) -> crate::Result<()> {
+        self.0.runtime.block_on(self.0.client.object().delete_with(
+            bucket,
+            file_name,
+            preconditions,
+        ))
+    }
+
+    /// Deletes all objects in the specified bucket whose names start with `prefix`, and returns
+    /// how many were deleted. See [`crate::client::ObjectClient::delete_prefix`].
+    pub fn delete_prefix(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        concurrency: usize,
+    ) -> crate::Result<usize> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object()
+                .delete_prefix(bucket, prefix, concurrency),
+        )
+    }
+
+    /// Restores a soft-deleted object with the specified name in the specified bucket. See
+    /// [`crate::client::ObjectClient::restore_soft_deleted`].
+    pub fn restore_soft_deleted(
+        &self,
+        bucket: &str,
+        file_name: &str,
+        generation: i64,
+        restore_token: Option<String>,
+    ) -> crate::Result<Object> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object().restore_soft_deleted(
+                bucket,
+                file_name,
+                generation,
+                restore_token,
+            ))
+    }
+
     /// Obtains a single object with the specified name in the specified bucket.
     /// ### Example
     /// ```no_run
@@ -204,6 +857,42 @@ impl<'a> ObjectClient<'a> {
         )
     }
 
+    /// Concatenates the contents of multiple objects into one, using the provided
+    /// `ComposeParameters`. These can be used to set preconditions on the destination object.
+    pub fn compose_with(
+        &self,
+        bucket: &str,
+        req: &ComposeRequest,
+        destination_object: &str,
+        parameters: ComposeParameters,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(self.0.client.object().compose_with(
+            bucket,
+            req,
+            destination_object,
+            parameters,
+        ))
+    }
+
+    /// Concatenates the contents of any number of objects into one, via intermediate objects when
+    /// there are more than 32 of them. The intermediate objects are deleted afterwards, also when
+    /// composing fails. The names of the ones that could not be deleted are returned along with the
+    /// composed object.
+    pub fn compose_all(
+        &self,
+        bucket: &str,
+        source_objects: Vec<SourceObject>,
+        destination_object: &str,
+        parameters: ComposeParameters,
+    ) -> crate::Result<(Object, Vec<String>)> {
+        self.0.runtime.block_on(self.0.client.object().compose_all(
+            bucket,
+            source_objects,
+            destination_object,
+            parameters,
+        ))
+    }
+
     /// Copy this object to the target bucket and path
     /// ### Example
     /// ```no_run
@@ -232,13 +921,31 @@ impl<'a> ObjectClient<'a> {
         )
     }
 
+    /// Copy this object to the target bucket and path, using the provided `CopyParameters`. These
+    /// can be used to copy a specific generation of the object, or to set preconditions on the
+    /// source and destination objects.
+    pub fn copy_with(
+        &self,
+        object: &Object,
+        destination_bucket: &str,
+        path: &str,
+        parameters: CopyParameters,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(self.0.client.object().copy_with(
+            object,
+            destination_bucket,
+            path,
+            parameters,
+        ))
+    }
+
     /// Moves a file from the current location to the target bucket and path.
     ///
     /// ## Limitations
     /// This function does not yet support rewriting objects to another
     /// * Geographical Location,
-    /// * Encryption,
     /// * Storage class.
+    ///
     /// These limitations mean that for now, the rewrite and the copy methods do the same thing.
     /// ### Example
     /// ```no_run
@@ -266,4 +973,22 @@ impl<'a> ObjectClient<'a> {
                 .rewrite(object, destination_bucket, path),
         )
     }
+
+    /// Moves a file from the current location to the target bucket and path, in the same way as
+    /// `rewrite`, using the provided `RewriteParameters`. These can be used to rewrite a specific
+    /// generation of the object, or to set preconditions on the source and destination objects.
+    pub fn rewrite_with(
+        &self,
+        object: &Object,
+        destination_bucket: &str,
+        path: &str,
+        parameters: RewriteParameters,
+    ) -> crate::Result<Object> {
+        self.0.runtime.block_on(self.0.client.object().rewrite_with(
+            object,
+            destination_bucket,
+            path,
+            parameters,
+        ))
+    }
 }
@@ -29,6 +29,30 @@ impl<'a> ObjectAccessControlClient<'a> {
             ))
     }
 
+    /// Creates a new ACL entry on the version of the specified `object` with the specified
+    /// `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform
+    /// bucket-level access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to
+    /// control access instead.
+    pub fn create_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+        new_object_access_control: &NewObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.0
+            .runtime
+            .block_on(self.0.client.object_access_control().create_for_generation(
+                bucket,
+                object,
+                generation,
+                new_object_access_control,
+            ))
+    }
+
     /// Retrieves `ACL` entries on the specified object.
     ///
     /// ### Important
@@ -41,6 +65,64 @@ impl<'a> ObjectAccessControlClient<'a> {
             .block_on(self.0.client.object_access_control().list(bucket, object))
     }
 
+    /// Retrieves `ACL` entries on the version of the specified object with the specified
+    /// `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn list_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .list_for_generation(bucket, object, generation),
+        )
+    }
+
+    /// Retrieves the complete `ACL` of the specified object, as part of the object resource. This
+    /// returns the same entries as `list`, and is the counterpart of `set_all`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn list_all(&self, bucket: &str, object: &str) -> crate::Result<Vec<ObjectAccessControl>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .list_all(bucket, object),
+        )
+    }
+
+    /// Replaces the complete `ACL` of the specified object with `acl` in a single request, and
+    /// returns the resulting entries. Entities that are not part of `acl` lose their access.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn set_all(
+        &self,
+        bucket: &str,
+        object: &str,
+        acl: &[NewObjectAccessControl],
+    ) -> crate::Result<Vec<ObjectAccessControl>> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .set_all(bucket, object, acl),
+        )
+    }
+
     /// Returns the `ACL` entry for the specified entity on the specified bucket.
     ///
     /// ### Important
@@ -61,7 +143,29 @@ impl<'a> ObjectAccessControlClient<'a> {
         )
     }
 
-    /// Updates an ACL entry on the specified object.
+    /// Returns the `ACL` entry for the specified entity on the version of the specified object
+    /// with the specified `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn read_for_generation(
+        &self,
+        bucket: &str,
+        object: &str,
+        generation: i64,
+        entity: &Entity,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .read_for_generation(bucket, object, generation, entity),
+        )
+    }
+
+    /// Updates an ACL entry on the live version of the specified object.
     ///
     /// ### Important
     /// Important: This method fails with a 400 Bad Request response for buckets with uniform
@@ -79,7 +183,28 @@ impl<'a> ObjectAccessControlClient<'a> {
         )
     }
 
-    /// Permanently deletes the ACL entry for the specified entity on the specified object.
+    /// Updates an ACL entry on the version of the specified object with the specified
+    /// `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn update_for_generation(
+        &self,
+        generation: i64,
+        object_access_control: &ObjectAccessControl,
+    ) -> crate::Result<ObjectAccessControl> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .update_for_generation(generation, object_access_control),
+        )
+    }
+
+    /// Permanently deletes the ACL entry for the specified entity on the live version of the
+    /// specified object.
     ///
     /// ### Important
     /// Important: This method fails with a 400 Bad Request response for buckets with uniform
@@ -93,4 +218,24 @@ impl<'a> ObjectAccessControlClient<'a> {
                 .delete(object_access_control),
         )
     }
+
+    /// Permanently deletes the ACL entry for the specified entity on the version of the specified
+    /// object with the specified `generation`.
+    ///
+    /// ### Important
+    /// This method fails with a 400 Bad Request response for buckets with uniform bucket-level
+    /// access enabled. Use `Bucket::get_iam_policy` and `Bucket::set_iam_policy` to control access
+    /// instead.
+    pub fn delete_for_generation(
+        &self,
+        generation: i64,
+        object_access_control: ObjectAccessControl,
+    ) -> crate::Result<()> {
+        self.0.runtime.block_on(
+            self.0
+                .client
+                .object_access_control()
+                .delete_for_generation(generation, object_access_control),
+        )
+    }
 }
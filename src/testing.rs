@@ -0,0 +1,155 @@
+//! Helpers for integration tests against Google Cloud Storage, for crates that build on this one.
+//! They are available with the `testing` feature.
+//!
+//! Tests usually run in parallel, and Google limits how often buckets can be created in a project.
+//! A `TestBucket` takes care of both: every bucket gets a unique name, and the creation of buckets
+//! is spaced out so that a test suite does not get rate limited.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{Bucket, Client, NewBucket};
+
+// Google allows about one bucket to be created per two seconds in a project.
+const CREATE_INTERVAL: Duration = Duration::from_secs(2);
+
+lazy_static::lazy_static! {
+    static ref LAST_CREATED: tokio::sync::Mutex<Option<Instant>> = tokio::sync::Mutex::new(None);
+}
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A bucket with a unique name that only exists for the duration of a test. Its objects and the
+/// bucket itself are deleted by `TestBucket::delete`, or when the `TestBucket` is dropped.
+///
+/// Deleting the bucket explicitly is preferred, since errors are reported that way. When it is
+/// dropped instead, the current thread blocks until the bucket is deleted, and errors are
+/// ignored. Noncurrent versions of objects are not deleted, so a bucket in which object
+/// versioning was enabled can not be deleted.
+/// ### Example
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use cloud_storage::testing::TestBucket;
+/// use cloud_storage::Client;
+///
+/// let client = Client::default();
+/// let bucket = TestBucket::create(&client, "my-crate-test").await?;
+/// client
+///     .object()
+///     .create(bucket.name(), b"hello".to_vec(), "hello.txt", "text/plain")
+///     .await?;
+/// bucket.delete().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TestBucket<'a> {
+    client: &'a Client,
+    bucket: Option<Bucket>,
+}
+
+impl<'a> TestBucket<'a> {
+    /// Creates a new bucket, named `prefix` followed by a unique suffix, in the project of
+    /// `client`. The suffix is 17 characters long, and bucket names can be at most 63 characters,
+    /// so `prefix` can be at most 46 characters.
+    pub async fn create(client: &'a Client, prefix: &str) -> crate::Result<TestBucket<'a>> {
+        let name = unique_name(prefix)?;
+        let mut last_created = LAST_CREATED.lock().await;
+        if let Some(last_created) = *last_created {
+            let elapsed = last_created.elapsed();
+            if elapsed < CREATE_INTERVAL {
+                tokio::time::sleep(CREATE_INTERVAL - elapsed).await;
+            }
+        }
+        let new_bucket = NewBucket {
+            name,
+            ..NewBucket::default()
+        };
+        let bucket = client.bucket().create(&new_bucket).await;
+        *last_created = Some(Instant::now());
+        Ok(Self {
+            client,
+            bucket: Some(bucket?),
+        })
+    }
+
+    /// The bucket, as it was when it was created.
+    pub fn bucket(&self) -> &Bucket {
+        // only `delete` and `drop` take the bucket out
+        self.bucket.as_ref().unwrap()
+    }
+
+    /// The name of the bucket.
+    pub fn name(&self) -> &str {
+        &self.bucket().name
+    }
+
+    /// Deletes all objects in the bucket, and then the bucket itself.
+    pub async fn delete(mut self) -> crate::Result<()> {
+        let bucket = self.bucket.take().unwrap();
+        delete(self.client, bucket).await
+    }
+}
+
+impl Drop for TestBucket<'_> {
+    fn drop(&mut self) {
+        let bucket = match self.bucket.take() {
+            Some(bucket) => bucket,
+            None => return,
+        };
+        // The connections of `client` belong to the runtime of the test, which cannot make
+        // progress while this thread is blocked, so the bucket is deleted on a runtime of its own.
+        let client = self.client.detached();
+        let name = bucket.name.clone();
+        let deleted = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?;
+            runtime.block_on(delete(&client, bucket))
+        })
+        .join();
+        match deleted {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => log::warn!("failed to delete test bucket {}: {}", name, e),
+            Err(_) => log::warn!("failed to delete test bucket {}", name),
+        }
+    }
+}
+
+// Deletes the objects in `bucket`, and then `bucket` itself.
+async fn delete(client: &Client, bucket: Bucket) -> crate::Result<()> {
+    client.object().delete_prefix(&bucket.name, "", 8).await?;
+    client.bucket().delete(bucket).await
+}
+
+// `prefix` followed by the current time and a counter, which is unique within the process and
+// very likely unique across processes.
+fn unique_name(prefix: &str) -> crate::Result<String> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| crate::Error::Other(e.to_string()))?
+        .as_nanos() as u64;
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed) % 0x100;
+    let name = format!("{}-{:014x}{:02x}", prefix, nanos >> 8, count);
+    if name.len() > 63 {
+        let msg = format!("the prefix {} of a test bucket is too long", prefix);
+        return Err(crate::Error::Other(msg));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_names() -> Result<(), Box<dyn std::error::Error>> {
+        let first = unique_name("my-crate-test")?;
+        let second = unique_name("my-crate-test")?;
+        assert_ne!(first, second);
+        assert!(first.starts_with("my-crate-test-"));
+        assert_eq!(first.len(), "my-crate-test-".len() + 16);
+        assert!(unique_name(&"a".repeat(47)).is_err());
+        Ok(())
+    }
+}
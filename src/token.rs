@@ -1,3 +1,4 @@
+use crate::resources::service_account::ServiceAccount;
 use std::fmt::{Display, Formatter};
 
 /// Trait that refreshes a token when it is expired
@@ -17,7 +18,7 @@ pub trait TokenCache: Sync {
     /// the token.
     async fn get(&self, client: &reqwest::Client) -> crate::Result<String> {
         match self.token_and_exp().await {
-            Some((token, exp)) if now() + 300 < exp => Ok(token),
+            Some((token, exp)) if is_fresh(exp) => Ok(token),
             _ => {
                 let (token, exp) = self.fetch_token(client).await?;
                 self.set_token(token, exp).await?;
@@ -34,6 +35,34 @@ pub trait TokenCache: Sync {
     async fn fetch_token(&self, client: &reqwest::Client) -> crate::Result<(String, u64)>;
 }
 
+/// The OAuth scope that tokens are requested for, which limits what the client is allowed to do.
+/// See [the Google Cloud Storage
+/// documentation](https://cloud.google.com/storage/docs/oauth-scopes) for more details.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Scope {
+    /// Only allows reading data and metadata of objects and buckets.
+    ReadOnly,
+    /// Allows reading and changing data, excluding access control lists.
+    ReadWrite,
+    /// Allows full control over data, including access control lists.
+    #[default]
+    FullControl,
+    /// Allows access to all Google Cloud services that the service account has access to.
+    CloudPlatform,
+}
+
+impl Scope {
+    /// The url that identifies this scope in a token request.
+    pub fn url(self) -> &'static str {
+        match self {
+            Scope::ReadOnly => "https://www.googleapis.com/auth/devstorage.read_only",
+            Scope::ReadWrite => "https://www.googleapis.com/auth/devstorage.read_write",
+            Scope::FullControl => "https://www.googleapis.com/auth/devstorage.full_control",
+            Scope::CloudPlatform => "https://www.googleapis.com/auth/cloud-platform",
+        }
+    }
+}
+
 #[derive(serde::Serialize)]
 struct Claims {
     iss: String,
@@ -58,6 +87,10 @@ pub struct Token {
     token: tokio::sync::RwLock<Option<DefaultTokenData>>,
     // store the access scope for later use if we need to refresh the token
     access_scope: String,
+    // the service account to sign token requests with, if not the one from the environment
+    service_account: Option<std::sync::Arc<ServiceAccount>>,
+    // how far the token requests are backdated, to allow for a clock that runs ahead
+    clock_skew: std::time::Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -71,7 +104,7 @@ impl Display for DefaultTokenData {
 
 impl Default for Token {
     fn default() -> Self {
-        Token::new("https://www.googleapis.com/auth/devstorage.full_control")
+        Token::new(Scope::default().url())
     }
 }
 
@@ -80,6 +113,40 @@ impl Token {
         Self {
             token: tokio::sync::RwLock::new(None),
             access_scope: scope.to_string(),
+            service_account: None,
+            clock_skew: std::time::Duration::ZERO,
+        }
+    }
+
+    /// Obtain tokens for `scope`, instead of for full control over storage.
+    pub fn with_scope(mut self, scope: Scope) -> Self {
+        self.access_scope = scope.url().to_string();
+        self
+    }
+
+    /// Use the provided service account to obtain tokens, instead of the one that is configured
+    /// through the environment.
+    pub fn with_service_account(
+        mut self,
+        service_account: impl Into<std::sync::Arc<ServiceAccount>>,
+    ) -> Self {
+        self.service_account = Some(service_account.into());
+        self
+    }
+
+    /// Dates token requests `clock_skew` in the past, to allow for a clock on this machine that
+    /// runs ahead of Google's. Without it, Google rejects the requests of such a machine as issued
+    /// in the future, and no token can be obtained. A few minutes is usually enough. Defaults to no
+    /// allowance.
+    pub fn with_clock_skew(mut self, clock_skew: std::time::Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    fn service_account(&self) -> &ServiceAccount {
+        match self.service_account {
+            Some(ref service_account) => service_account,
+            None => &crate::SERVICE_ACCOUNT,
         }
     }
 }
@@ -101,37 +168,75 @@ impl TokenCache for Token {
 
     async fn fetch_token(&self, client: &reqwest::Client) -> crate::Result<(String, u64)> {
         let now = now();
-        let exp = now + 3600;
+        // Google accepts assertions that are valid for at most an hour
+        let iat = now.saturating_sub(self.clock_skew.as_secs());
+        let exp = iat + 3600;
 
         let claims = Claims {
-            iss: crate::SERVICE_ACCOUNT.client_email.clone(),
+            iss: self.service_account().client_email.clone(),
             scope: self.scope().await,
             aud: "https://www.googleapis.com/oauth2/v4/token".to_string(),
             exp,
-            iat: now,
+            iat,
         };
         let header = jsonwebtoken::Header {
             alg: jsonwebtoken::Algorithm::RS256,
             ..Default::default()
         };
-        let private_key_bytes = crate::SERVICE_ACCOUNT.private_key.as_bytes();
+        let private_key_bytes = self.service_account().private_key.as_bytes();
         let private_key = jsonwebtoken::EncodingKey::from_rsa_pem(private_key_bytes)?;
         let jwt = jsonwebtoken::encode(&header, &claims, &private_key)?;
         let body = [
             ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
             ("assertion", &jwt),
         ];
-        let response: TokenResponse = client
+        let request = client
             .post("https://www.googleapis.com/oauth2/v4/token")
-            .form(&body)
-            .send()
-            .await?
-            .json()
-            .await?;
+            .form(&body);
+        let response = crate::client::send(client, None, request).await?;
+        if !response.status().is_success() {
+            return Err(crate::Error::Auth(format!(
+                "token request was rejected with status {}: {}",
+                response.status(),
+                response.text().await?,
+            )));
+        }
+        let response: TokenResponse = response.json().await?;
         Ok((response.access_token, now + response.expires_in))
     }
 }
 
+/// A `TokenCache` for anonymous access, which never obtains a token. Requests made by a client
+/// with this cache carry no `Authorization` header, so they only succeed for resources that are
+/// publicly accessible, such as the objects in a public bucket. See [`crate::Client::anonymous`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnonymousToken;
+
+#[async_trait::async_trait]
+impl TokenCache for AnonymousToken {
+    async fn token_and_exp(&self) -> Option<(String, u64)> {
+        Some((String::new(), u64::MAX))
+    }
+
+    async fn set_token(&self, _token: String, _exp: u64) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn scope(&self) -> String {
+        String::new()
+    }
+
+    async fn fetch_token(&self, _client: &reqwest::Client) -> crate::Result<(String, u64)> {
+        Ok((String::new(), u64::MAX))
+    }
+}
+
+// Whether a token that expires at `exp` can still be used, leaving some margin for the requests
+// that it is used for.
+pub(crate) fn is_fresh(exp: u64) -> bool {
+    now() + 300 < exp
+}
+
 fn now() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::SystemTime::UNIX_EPOCH)
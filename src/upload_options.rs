@@ -0,0 +1,90 @@
+use crate::object::CustomerEncryptionKey;
+
+/// A set of parameters that can be used to customise object uploads.
+#[derive(Debug, Default, Clone)]
+pub struct UploadOptions {
+    pub(crate) gzip: bool,
+    pub(crate) kms_key_name: Option<String>,
+    pub(crate) encryption_key: Option<CustomerEncryptionKey>,
+}
+
+impl UploadOptions {
+    /// Create a new instance of `UploadOptions`. Equivalent to `UploadOptions::default()`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::UploadOptions;
+    ///
+    /// let opts = UploadOptions::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compress the data with gzip before uploading it. The object is stored compressed, with its
+    /// `content_encoding` set to `gzip` and its `content_type` set to the mime type of the
+    /// uncompressed data. This way, Google serves the decompressed data to clients that do not
+    /// accept gzip, while clients that do receive the compressed bytes.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::UploadOptions;
+    ///
+    /// let opts = UploadOptions::new()
+    ///     .gzip(true);
+    /// ```
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    /// Encrypt the object with the Cloud KMS key with the resource name `kms_key_name`, in the
+    /// form `projects/{project}/locations/{location}/keyRings/{ring}/cryptoKeys/{key}`, instead
+    /// of the default key of the bucket.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::UploadOptions;
+    ///
+    /// let opts = UploadOptions::new()
+    ///     .kms_key_name("projects/p/locations/eu/keyRings/r/cryptoKeys/k");
+    /// ```
+    pub fn kms_key_name(mut self, kms_key_name: &str) -> Self {
+        self.kms_key_name = Some(kms_key_name.to_string());
+        self
+    }
+
+    /// Encrypt the object with a customer-supplied encryption key. The same key must be supplied
+    /// to read the metadata of the object, or to download, copy or rewrite it, using the
+    /// `encryption_key` of `ReadParameters`, `CopyParameters` or `RewriteParameters`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use cloud_storage::object::CustomerEncryptionKey;
+    /// use cloud_storage::UploadOptions;
+    ///
+    /// let key = CustomerEncryptionKey::new([7; 32]);
+    /// let opts = UploadOptions::new()
+    ///     .encryption_key(key);
+    /// ```
+    pub fn encryption_key(mut self, encryption_key: CustomerEncryptionKey) -> Self {
+        self.encryption_key = Some(encryption_key);
+        self
+    }
+
+    // The headers that the requests of the upload carry.
+    pub(crate) fn headers(&self) -> crate::Result<reqwest::header::HeaderMap> {
+        match self.encryption_key {
+            Some(ref encryption_key) => encryption_key.headers(),
+            None => Ok(reqwest::header::HeaderMap::new()),
+        }
+    }
+
+    // The query parameters that the request that creates the object carries.
+    pub(crate) fn query(&self) -> Vec<(&'static str, &str)> {
+        match self.kms_key_name {
+            Some(ref kms_key_name) => vec![("kmsKeyName", kms_key_name)],
+            None => Vec::new(),
+        }
+    }
+}
@@ -5,3 +5,8 @@ echo && echo '--------------------------------' && echo 'Runing sync tests with
 cargo test --no-default-features --features sync,rustls-tls,global-client -- --test-threads=1
 echo && echo '--------------------------------' && echo 'Runing sync tests with all features'
 cargo test --all-features -- --test-threads=1
+echo && echo '--------------------------------' && echo 'Checking the build without openssl'
+cargo test --no-default-features --features rustls-tls --lib
+if cargo tree --no-default-features --features rustls-tls --edges normal -i openssl-sys > /dev/null 2>&1; then
+    echo 'the rustls-tls build depends on openssl' && exit 1
+fi